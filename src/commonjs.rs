@@ -0,0 +1,109 @@
+use swc_core::{
+    common::{Span, SyntaxContext},
+    ecma::{
+        ast::*,
+        utils::private_ident,
+        visit::{noop_visit_mut_type, VisitMut, VisitMutWith},
+    },
+};
+use tracing::debug;
+
+/// Collect `require()` calls and `module` / `exports` references of the CommonJS module.
+///
+/// If `rename_module` is `true`, free `module` and `exports` are renamed to the private identifiers
+/// that are declared by the plugin (runtime module).
+pub struct CommonJsCollector {
+    pub requires: Vec<String>,
+    pub module_ident: Ident,
+    pub exports_ident: Ident,
+    // The first `module` / `exports` reference
+    pub module_ref_span: Option<Span>,
+    unresolved_ctxt: SyntaxContext,
+    rename_module: bool,
+}
+
+impl CommonJsCollector {
+    pub fn new(unresolved_ctxt: SyntaxContext, rename_module: bool) -> Self {
+        CommonJsCollector {
+            requires: Vec::new(),
+            module_ident: private_ident!("__module"),
+            exports_ident: private_ident!("__exports"),
+            module_ref_span: None,
+            unresolved_ctxt,
+            rename_module,
+        }
+    }
+
+    pub fn is_cjs(&self) -> bool {
+        self.module_ref_span.is_some() || !self.requires.is_empty()
+    }
+
+    fn get_renamed_ident(&mut self, ident: &Ident) -> Option<Ident> {
+        if ident.span.ctxt != self.unresolved_ctxt {
+            return None;
+        }
+        let renamed_ident = match &*ident.sym {
+            "module" => &self.module_ident,
+            "exports" => &self.exports_ident,
+            _ => return None,
+        };
+        self.module_ref_span.get_or_insert(ident.span);
+        self.rename_module.then(|| renamed_ident.clone())
+    }
+}
+
+/// Returns the module source if the call expression is `require('module')`.
+pub fn get_require_module_src(
+    call_expr: &CallExpr,
+    unresolved_ctxt: SyntaxContext,
+) -> Option<&Str> {
+    match call_expr {
+        CallExpr {
+            callee: Callee::Expr(callee),
+            args,
+            ..
+        } if args.len() == 1 => match (&**callee, &*args[0].expr) {
+            (Expr::Ident(callee_ident), Expr::Lit(Lit::Str(module_src)))
+                if &*callee_ident.sym == "require" && callee_ident.span.ctxt == unresolved_ctxt =>
+            {
+                Some(module_src)
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+impl VisitMut for CommonJsCollector {
+    noop_visit_mut_type!();
+
+    fn visit_mut_call_expr(&mut self, call_expr: &mut CallExpr) {
+        if let Some(module_src) = get_require_module_src(call_expr, self.unresolved_ctxt) {
+            debug!("require: {:#?}", module_src.value);
+            if !self.requires.contains(&module_src.value.to_string()) {
+                self.requires.push(module_src.value.to_string());
+            }
+        }
+        call_expr.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_ident(&mut self, ident: &mut Ident) {
+        if let Some(renamed_ident) = self.get_renamed_ident(ident) {
+            *ident = renamed_ident;
+        }
+    }
+
+    // `{ exports }` to `{ exports: __exports }`
+    fn visit_mut_prop(&mut self, prop: &mut Prop) {
+        if let Prop::Shorthand(ident) = prop {
+            if let Some(renamed_ident) = self.get_renamed_ident(ident) {
+                *prop = Prop::KeyValue(KeyValueProp {
+                    key: PropName::Ident(ident.clone()),
+                    value: Box::new(Expr::Ident(renamed_ident)),
+                });
+            }
+            return;
+        }
+        prop.visit_mut_children_with(self);
+    }
+}
@@ -0,0 +1,15 @@
+use swc_core::common::{errors::HANDLER, Span};
+
+/// Report the unsupported (or lossy) module syntax through swc diagnostics.
+///
+/// Reported as an error if `strict` is `true`, otherwise as a warning.
+pub fn report_unsupported(span: Span, message: &str, strict: bool) {
+    let message = format!("{}: {}", crate::PLUGIN_NAME, message);
+    HANDLER.with(|handler| {
+        if strict {
+            handler.struct_span_err(span, &message).emit();
+        } else {
+            handler.struct_span_warn(span, &message).emit();
+        }
+    });
+}
@@ -0,0 +1,68 @@
+use swc_core::{
+    common::SyntaxContext,
+    ecma::{
+        ast::*,
+        visit::{noop_visit_mut_type, VisitMut, VisitMutWith},
+    },
+};
+use tracing::debug;
+
+/// Replace references of the HMR context with the context of the module.
+///
+/// ```js
+/// import.meta.hot.accept();
+/// module.hot.accept();
+/// // to
+/// __hot.accept();
+/// ```
+pub struct HotContextReplacer {
+    pub hot_ident: Ident,
+    unresolved_ctxt: SyntaxContext,
+    pub is_referenced: bool,
+}
+
+impl HotContextReplacer {
+    pub fn new(hot_ident: Ident, unresolved_ctxt: SyntaxContext) -> Self {
+        HotContextReplacer {
+            hot_ident,
+            unresolved_ctxt,
+            is_referenced: false,
+        }
+    }
+
+    // `import.meta.hot` or `module.hot` (`module` is not declared in the module)
+    fn is_hot_context_expr(&self, member_expr: &MemberExpr) -> bool {
+        let is_hot_prop =
+            matches!(&member_expr.prop, MemberProp::Ident(prop) if &*prop.sym == "hot");
+        is_hot_prop
+            && match &*member_expr.obj {
+                Expr::MetaProp(MetaPropExpr {
+                    kind: MetaPropKind::ImportMeta,
+                    ..
+                }) => true,
+                Expr::Ident(ident) => {
+                    &*ident.sym == "module" && ident.span.ctxt == self.unresolved_ctxt
+                }
+                _ => false,
+            }
+    }
+}
+
+impl VisitMut for HotContextReplacer {
+    noop_visit_mut_type!();
+
+    fn visit_mut_expr(&mut self, expr: &mut Expr) {
+        if let Expr::Member(member_expr) = expr {
+            if self.is_hot_context_expr(member_expr) {
+                debug!("hot context: {:#?}", member_expr.span);
+                self.is_referenced = true;
+                *expr = Expr::Ident(Ident::new(
+                    self.hot_ident.sym.clone(),
+                    member_expr.span.with_ctxt(self.hot_ident.span.ctxt),
+                ));
+                return;
+            }
+        }
+        expr.visit_mut_children_with(self);
+    }
+}
@@ -0,0 +1,90 @@
+use crate::module_path::is_package_specifier;
+use crate::utils::{num_lit_expr, str_lit_expr};
+use serde_json::Value;
+use std::{fs, path::Path};
+use swc_core::{common::DUMMY_SP, ecma::ast::*};
+use tracing::debug;
+
+/// Returns `true` if the import attributes are `{ type: 'json' }`.
+pub fn is_json_attributes(attributes: &ObjectLit) -> bool {
+    attributes.props.iter().any(|prop| match prop {
+        PropOrSpread::Prop(prop) => match &**prop {
+            Prop::KeyValue(KeyValueProp { key, value }) => {
+                let is_type_key = match key {
+                    PropName::Ident(ident) => &*ident.sym == "type",
+                    PropName::Str(str) => &*str.value == "type",
+                    _ => false,
+                };
+                is_type_key && matches!(&**value, Expr::Lit(Lit::Str(str)) if &*str.value == "json")
+            }
+            _ => false,
+        },
+        _ => false,
+    })
+}
+
+/// Read the JSON module that is imported by `filename` and convert it to the expression.
+///
+/// Returns `None` if the file can't be read or parsed (eg. the plugin has no access to the file system),
+/// then the module is imported from the registry as usual.
+pub fn load_json_module(filename: &str, module_src: &str) -> Option<Expr> {
+    if is_package_specifier(module_src) {
+        return None;
+    }
+
+    let path = Path::new(filename)
+        .parent()
+        .unwrap_or_else(|| Path::new(""))
+        .join(module_src);
+    let value = fs::read_to_string(&path)
+        .ok()
+        .and_then(|source| serde_json::from_str::<Value>(&source).ok());
+
+    debug!("json module: {:#?} (loaded: {})", path, value.is_some());
+    value.map(json_expr)
+}
+
+fn json_expr(value: Value) -> Expr {
+    match value {
+        Value::Null => Expr::Lit(Lit::Null(Null { span: DUMMY_SP })),
+        Value::Bool(value) => Expr::Lit(Lit::Bool(Bool {
+            span: DUMMY_SP,
+            value,
+        })),
+        Value::Number(value) => num_lit_expr(value.as_f64().unwrap_or_default()),
+        Value::String(value) => str_lit_expr(value),
+        Value::Array(values) => Expr::Array(ArrayLit {
+            span: DUMMY_SP,
+            elems: values
+                .into_iter()
+                .map(|value| Some(json_expr(value).into()))
+                .collect(),
+        }),
+        Value::Object(entries) => Expr::Object(ObjectLit {
+            span: DUMMY_SP,
+            props: entries
+                .into_iter()
+                .map(|(key, value)| {
+                    // `"__proto__": ...` sets the prototype in object literals,
+                    // `["__proto__"]: ...` defines the own property like `JSON.parse`.
+                    let key = if key == "__proto__" {
+                        PropName::Computed(ComputedPropName {
+                            span: DUMMY_SP,
+                            expr: Box::new(str_lit_expr(key)),
+                        })
+                    } else {
+                        PropName::Str(Str {
+                            span: DUMMY_SP,
+                            value: key.into(),
+                            raw: None,
+                        })
+                    };
+                    PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                        key,
+                        value: Box::new(json_expr(value)),
+                    })))
+                })
+                .collect(),
+        }),
+    }
+}
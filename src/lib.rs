@@ -1,202 +1,1256 @@
+mod commonjs;
+mod diagnostics;
+mod hmr;
+mod json_module;
+mod live_binding;
 mod module_collector;
+mod module_id;
+mod module_path;
 mod utils;
 
+use commonjs::{get_require_module_src, CommonJsCollector};
+use diagnostics::report_unsupported;
+use hmr::HotContextReplacer;
+use json_module::{is_json_attributes, load_json_module};
+use live_binding::LiveBindingReplacer;
 use module_collector::{ExportModule, ImportModule, ModuleCollector, ModuleType};
+use module_id::{ModuleId, ModuleIdResolver, ModuleIdStrategy};
+use module_path::{is_package_specifier, normalize_module_path, resolve_module_src};
 use serde::Deserialize;
-use swc_core::common::Span;
+use std::collections::{HashMap, HashSet};
+use swc_core::common::{comments::Comments, errors::HANDLER, Mark, Span, Spanned, SyntaxContext};
 use swc_core::plugin::{plugin_transform, proxies::TransformPluginProgramMetadata};
+use swc_core::quote;
 use swc_core::{
-    atoms::js_word,
+    atoms::{js_word, Atom},
     common::DUMMY_SP,
     ecma::{
         ast::*,
+        utils::{contains_top_level_await, is_valid_prop_ident, private_ident},
         visit::{as_folder, noop_visit_mut_type, FoldWith, VisitMut, VisitMutWith},
     },
     plugin::metadata::TransformPluginMetadataContextKind,
 };
+use tracing::debug;
 use utils::{
-    call_expr, decl_var_and_assign_stmt, fn_arg, ident, ident_expr, obj_member_expr, str_lit_expr,
+    call_expr, decl_var_and_assign_stmt, directive_prologue_len, fn_arg,
+    group_imports_by_module_src, ident, ident_expr, module_namespace_ident, num_lit_expr,
+    obj_member_expr, obj_prop_expr, str_lit_expr,
 };
 
+const PLUGIN_NAME: &str = "swc-plugin-react-native-esbuild-module";
 const GLOBAL: &str = "global";
 const MODULE: &str = "__modules";
+// Default method names of the module registry
 const MODULE_IMPORT_METHOD_NAME: &str = "import";
 const MODULE_EXPORT_METHOD_NAME: &str = "export";
+const MODULE_IMPORT_ASYNC_METHOD_NAME: &str = "importAsync";
+const MODULE_DEFINE_METHOD_NAME: &str = "define";
+const MODULE_HOT_METHOD_NAME: &str = "hot";
+
+#[derive(Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum Target {
+    // `global`
+    #[default]
+    ReactNative,
+    // `globalThis`, `self` or `window` (looked up once per module)
+    Web,
+}
+
+enum RegistryMethod {
+    Import,
+    Export,
+    ImportAsync,
+    Define,
+    Hot,
+}
 
 #[derive(Deserialize)]
-#[serde(rename_all = "camelCase")]
-struct ReactNativeEsbuildModuleOptions {
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct RegistryMethodNames {
+    import: String,
+    export: String,
+    import_async: String,
+    define: String,
+    hot: String,
+}
+
+impl Default for RegistryMethodNames {
+    fn default() -> Self {
+        RegistryMethodNames {
+            import: MODULE_IMPORT_METHOD_NAME.into(),
+            export: MODULE_EXPORT_METHOD_NAME.into(),
+            import_async: MODULE_IMPORT_ASYNC_METHOD_NAME.into(),
+            define: MODULE_DEFINE_METHOD_NAME.into(),
+            hot: MODULE_HOT_METHOD_NAME.into(),
+        }
+    }
+}
+
+impl RegistryMethodNames {
+    fn get(&self, method: RegistryMethod) -> &str {
+        match method {
+            RegistryMethod::Import => &self.import,
+            RegistryMethod::Export => &self.export,
+            RegistryMethod::ImportAsync => &self.import_async,
+            RegistryMethod::Define => &self.define,
+            RegistryMethod::Hot => &self.hot,
+        }
+    }
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ReactNativeEsbuildModuleOptions {
     runtime_module: Option<bool>,
+    live_bindings: Option<bool>,
+    live_exports: Option<bool>,
+    factory: Option<bool>,
+    commonjs: Option<bool>,
+    es_module_interop: Option<bool>,
+    inline_json: Option<bool>,
+    strict: Option<bool>,
+    hmr: Option<bool>,
+    prelude: Option<String>,
+    root: Option<String>,
+    module_id: Option<ModuleIdStrategy>,
+    module_id_map: Option<HashMap<String, u32>>,
+    target: Option<Target>,
+    global_object: Option<String>,
+    registry_name: Option<String>,
+    method_names: Option<RegistryMethodNames>,
 }
 
 pub struct ReactNativeEsbuildModule {
     module_name: String,
     runtime_module: bool,
+    live_bindings: bool,
+    live_exports: bool,
+    factory: bool,
+    commonjs: bool,
+    es_module_interop: bool,
+    inline_json: bool,
+    strict: bool,
+    hmr: bool,
+    // Normalized path of the module that the registry is emitted to
+    prelude: Option<String>,
+    // `None` to look up the global object at runtime (web target)
+    global_object: Option<Atom>,
+    registry_name: Atom,
+    method_names: RegistryMethodNames,
+    module_id_resolver: ModuleIdResolver,
+    // Module ids that are reported as missing from `moduleIdMap`
+    missing_module_ids: HashSet<String>,
+    unresolved_ctxt: SyntaxContext,
+    interop_default_ident: Option<Ident>,
+    export_star_ident: Option<Ident>,
+    global_ident: Option<Ident>,
+    // Comments of the original module to carry to the transformed statements
+    comments: Option<Box<dyn Comments>>,
 }
 
 impl ReactNativeEsbuildModule {
-    fn get_custom_import_expr(&mut self, module_name: String) -> Expr {
-        call_expr(
-            obj_member_expr(
-                obj_member_expr(ident_expr(js_word!(GLOBAL)), ident(js_word!(MODULE))),
-                Ident::new(js_word!(MODULE_IMPORT_METHOD_NAME), DUMMY_SP),
+    pub fn new(
+        module_name: String,
+        unresolved_mark: Mark,
+        options: ReactNativeEsbuildModuleOptions,
+    ) -> Self {
+        ReactNativeEsbuildModule {
+            module_name,
+            runtime_module: options.runtime_module.unwrap_or(false),
+            live_bindings: options.live_bindings.unwrap_or(false),
+            live_exports: options.live_exports.unwrap_or(false),
+            factory: options.factory.unwrap_or(false),
+            commonjs: options.commonjs.unwrap_or(false),
+            es_module_interop: options.es_module_interop.unwrap_or(false),
+            inline_json: options.inline_json.unwrap_or(false),
+            strict: options.strict.unwrap_or(false),
+            hmr: options.hmr.unwrap_or(false),
+            prelude: options.prelude.map(|prelude| match &options.root {
+                Some(root) if !prelude.starts_with('/') => {
+                    normalize_module_path(&format!("{}/{}", root, prelude))
+                }
+                _ => normalize_module_path(&prelude),
+            }),
+            global_object: match (options.global_object, options.target.unwrap_or_default()) {
+                (Some(global_object), _) => Some(global_object.into()),
+                (None, Target::ReactNative) => Some(js_word!(GLOBAL)),
+                (None, Target::Web) => None,
+            },
+            registry_name: options.registry_name.as_deref().unwrap_or(MODULE).into(),
+            method_names: options.method_names.unwrap_or_default(),
+            module_id_resolver: ModuleIdResolver::new(
+                options.module_id.unwrap_or_default(),
+                options.root,
+                options.module_id_map,
             ),
-            vec![fn_arg(str_lit_expr(module_name))],
+            missing_module_ids: HashSet::new(),
+            unresolved_ctxt: SyntaxContext::empty().apply_mark(unresolved_mark),
+            interop_default_ident: None,
+            export_star_ident: None,
+            global_ident: None,
+            comments: None,
+        }
+    }
+
+    pub fn with_comments(mut self, comments: impl Comments + 'static) -> Self {
+        self.comments = Some(Box::new(comments));
+        self
+    }
+
+    fn is_prelude_module(&self) -> bool {
+        self.prelude
+            .as_ref()
+            .is_some_and(|prelude| *prelude == normalize_module_path(&self.module_name))
+    }
+
+    fn get_global_expr(&mut self) -> Expr {
+        match &self.global_object {
+            Some(global_object) => ident_expr(global_object.clone()),
+            None => Expr::Ident(
+                self.global_ident
+                    .get_or_insert_with(|| private_ident!("__global"))
+                    .clone(),
+            ),
+        }
+    }
+
+    // ```js
+    // var __global = typeof globalThis !== 'undefined' ? globalThis : ...;
+    // ```
+    fn take_global_stmt(&mut self) -> Option<ModuleItem> {
+        self.global_ident.take().map(|global_ident| {
+            quote!(
+                "var $global = typeof globalThis !== 'undefined'
+                    ? globalThis
+                    : typeof self !== 'undefined'
+                    ? self
+                    : typeof window !== 'undefined'
+                    ? window
+                    : global;" as ModuleItem,
+                global = global_ident,
+            )
+        })
+    }
+
+    // The module registry that implements the methods called by the transformed modules.
+    fn get_runtime_stmt(&mut self) -> Stmt {
+        let global_expr = self.get_global_expr();
+        quote!(
+            "(function (global) {
+                var registry = global[$registry];
+                if (registry) {
+                    return;
+                }
+                var hasOwn = Object.prototype.hasOwnProperty;
+                var modules = {};
+                var factories = {};
+                var evaluating = {};
+                var hotContexts = {};
+                var hotCallbacks = {};
+                var hotData = {};
+                registry = global[$registry] = {};
+                registry[$import] = function (id) {
+                    if (hasOwn.call(modules, id)) {
+                        return modules[id];
+                    }
+                    if (hasOwn.call(factories, id)) {
+                        var factory = factories[id];
+                        // Register the exports before evaluating the module
+                        // so that circular imports get the partial exports.
+                        evaluating[id] = modules[id] = {};
+                        try {
+                            factory();
+                        } catch (error) {
+                            delete modules[id];
+                            throw error;
+                        } finally {
+                            delete evaluating[id];
+                        }
+                        return modules[id];
+                    }
+                    throw new Error('\"' + id + '\" module not found');
+                };
+                registry[$export] = function (id, exports) {
+                    var partialExports = evaluating[id];
+                    if (partialExports && partialExports === modules[id] && exports && typeof exports === 'object') {
+                        Object.getOwnPropertyNames(exports).forEach(function (name) {
+                            Object.defineProperty(partialExports, name, Object.getOwnPropertyDescriptor(exports, name));
+                        });
+                        return partialExports;
+                    }
+                    return modules[id] = exports;
+                };
+                registry[$import_async] = function (id) {
+                    return new Promise(function (resolve) {
+                        resolve(registry[$import](id));
+                    });
+                };
+                registry[$define] = function (id, deps, factory) {
+                    factories[id] = factory;
+                    if (hasOwn.call(hotContexts, id)) {
+                        var acceptCallbacks = hotCallbacks[id].accept;
+                        hotContexts[id].invalidate();
+                        var exports = registry[$import](id);
+                        acceptCallbacks.forEach(function (callback) {
+                            callback(exports);
+                        });
+                    }
+                };
+                registry[$hot] = function (id) {
+                    if (hasOwn.call(hotContexts, id)) {
+                        return hotContexts[id];
+                    }
+                    var callbacks = hotCallbacks[id] = { accept: [], dispose: [] };
+                    var data = hasOwn.call(hotData, id) ? hotData[id] : hotData[id] = {};
+                    return hotContexts[id] = {
+                        data: data,
+                        accept: function (callback) {
+                            typeof callback === 'function' && callbacks.accept.push(callback);
+                        },
+                        dispose: function (callback) {
+                            callbacks.dispose.push(callback);
+                        },
+                        invalidate: function () {
+                            delete hotContexts[id];
+                            // The module is evaluated again on the next import.
+                            if (hasOwn.call(factories, id)) {
+                                delete modules[id];
+                            }
+                            callbacks.dispose.forEach(function (callback) {
+                                callback(data);
+                            });
+                        },
+                    };
+                };
+            })($global);" as Stmt,
+            global: Expr = global_expr,
+            registry: Expr = str_lit_expr(self.registry_name.to_string()),
+            import: Expr = str_lit_expr(self.method_names.import.clone()),
+            export: Expr = str_lit_expr(self.method_names.export.clone()),
+            import_async: Expr = str_lit_expr(self.method_names.import_async.clone()),
+            define: Expr = str_lit_expr(self.method_names.define.clone()),
+            hot: Expr = str_lit_expr(self.method_names.hot.clone()),
+        )
+    }
+
+    // `global.__modules.method`
+    fn get_registry_method_expr(&mut self, method: RegistryMethod) -> Expr {
+        let global_expr = self.get_global_expr();
+        obj_prop_expr(
+            obj_prop_expr(global_expr, self.registry_name.clone()),
+            self.method_names.get(method).into(),
         )
     }
 
-    fn get_custom_export_expr(&mut self, export_expr: Expr) -> Expr {
+    fn get_module_id_expr(&self, module_id: ModuleId) -> Expr {
+        match module_id {
+            ModuleId::Str(module_id) => str_lit_expr(module_id),
+            ModuleId::Num(module_id) => num_lit_expr(module_id.into()),
+        }
+    }
+
+    // Modules missing from `moduleIdMap` fall back to the module path (reported once per module).
+    fn get_module_id(&mut self, module_path: &str, is_package: bool, span: Span) -> ModuleId {
+        let module_id = self.module_id_resolver.get(module_path, is_package);
+        if let ModuleId::Str(module_id) = &module_id {
+            if self.module_id_resolver.strategy() == ModuleIdStrategy::Numeric
+                && self.missing_module_ids.insert(module_id.clone())
+            {
+                report_unsupported(
+                    span,
+                    &format!(
+                        "`{}` is not in `moduleIdMap`, the module path is used as the module id",
+                        module_id
+                    ),
+                    self.strict,
+                );
+            }
+        }
+        module_id
+    }
+
+    fn get_import_module_id_expr(&mut self, module_src: &str, span: Span) -> Expr {
+        let module_id = self.get_module_id(
+            &resolve_module_src(&self.module_name, module_src),
+            is_package_specifier(module_src),
+            span,
+        );
+        self.get_module_id_expr(module_id)
+    }
+
+    fn get_export_module_id_expr(&mut self, span: Span) -> Expr {
+        let module_id = self.get_module_id(&normalize_module_path(&self.module_name), false, span);
+        self.get_module_id_expr(module_id)
+    }
+
+    // `global.__modules.import("id")` or `global.__modules.import("id", { type: "json" })`
+    fn get_custom_import_expr(
+        &mut self,
+        module_name: String,
+        attributes: Option<Box<ObjectLit>>,
+        span: Span,
+    ) -> Expr {
+        let mut args = vec![fn_arg(self.get_import_module_id_expr(&module_name, span))];
+        if let Some(attributes) = attributes {
+            args.push(fn_arg(Expr::Object(*attributes)));
+        }
         call_expr(
-            obj_member_expr(
-                obj_member_expr(ident_expr(js_word!(GLOBAL)), ident(js_word!(MODULE))),
-                Ident::new(js_word!(MODULE_EXPORT_METHOD_NAME), DUMMY_SP),
-            ),
+            span,
+            self.get_registry_method_expr(RegistryMethod::Import),
+            args,
+        )
+    }
+
+    fn get_custom_export_expr(&mut self, export_expr: Expr, span: Span) -> Expr {
+        call_expr(
+            span,
+            self.get_registry_method_expr(RegistryMethod::Export),
             vec![
-                fn_arg(str_lit_expr(self.module_name.to_owned())),
+                fn_arg(self.get_export_module_id_expr(span)),
                 fn_arg(export_expr),
             ],
         )
     }
 
-    fn default_import_stmt(&mut self, module_name: String, span: Span, ident: Ident) -> Stmt {
+    // `global.__modules.define("id", ["dep"], function () { ... });`
+    fn get_custom_define_stmt(&mut self, deps: Vec<String>, body: Vec<Stmt>) -> Stmt {
+        let deps_expr = Expr::Array(ArrayLit {
+            span: DUMMY_SP,
+            elems: deps
+                .iter()
+                .map(|dep| Some(fn_arg(self.get_import_module_id_expr(dep, DUMMY_SP))))
+                .collect(),
+        });
+        let factory_expr = Expr::Fn(FnExpr {
+            ident: None,
+            function: Box::new(Function {
+                params: Vec::new(),
+                decorators: Vec::new(),
+                span: DUMMY_SP,
+                body: Some(BlockStmt {
+                    span: DUMMY_SP,
+                    stmts: body,
+                }),
+                is_generator: false,
+                is_async: false,
+                type_params: None,
+                return_type: None,
+            }),
+        });
+
+        Stmt::Expr(ExprStmt {
+            span: DUMMY_SP,
+            expr: Box::new(call_expr(
+                DUMMY_SP,
+                self.get_registry_method_expr(RegistryMethod::Define),
+                vec![
+                    fn_arg(self.get_export_module_id_expr(DUMMY_SP)),
+                    fn_arg(deps_expr),
+                    fn_arg(factory_expr),
+                ],
+            )),
+        })
+    }
+
+    // `__interopDefault(expr)`
+    fn get_interop_default_expr(&mut self, expr: Expr) -> Expr {
+        let interop_default_ident = self
+            .interop_default_ident
+            .get_or_insert_with(|| private_ident!("__interopDefault"))
+            .clone();
+        call_expr(
+            DUMMY_SP,
+            Expr::Ident(interop_default_ident),
+            vec![fn_arg(expr)],
+        )
+    }
+
+    fn default_import_stmt(
+        &mut self,
+        module_name: String,
+        attributes: Option<Box<ObjectLit>>,
+        span: Span,
+        ident: Ident,
+    ) -> Stmt {
+        let mut import_expr = self.get_custom_import_expr(module_name, attributes, span);
+        if self.es_module_interop {
+            import_expr = self.get_interop_default_expr(import_expr);
+        }
         decl_var_and_assign_stmt(
             ident,
             span,
-            obj_member_expr(
-                self.get_custom_import_expr(module_name),
-                Ident::new("default".into(), DUMMY_SP),
-            ),
+            obj_member_expr(import_expr, Ident::new("default".into(), DUMMY_SP)),
         )
     }
 
-    fn named_import_stmt(&mut self, module_name: String, span: Span, ident: Ident) -> Stmt {
+    fn named_import_stmt(
+        &mut self,
+        module_name: String,
+        attributes: Option<Box<ObjectLit>>,
+        span: Span,
+        ident: Ident,
+        imported_name: Atom,
+    ) -> Stmt {
         decl_var_and_assign_stmt(
-            ident.clone(),
+            ident,
             span,
-            obj_member_expr(
-                self.get_custom_import_expr(module_name),
-                Ident::new(ident.sym, DUMMY_SP),
+            obj_prop_expr(
+                self.get_custom_import_expr(module_name, attributes, span),
+                imported_name,
             ),
         )
     }
 
-    fn namespace_import_stmt(&mut self, module_name: String, span: Span, ident: Ident) -> Stmt {
+    fn namespace_import_stmt(
+        &mut self,
+        module_name: String,
+        attributes: Option<Box<ObjectLit>>,
+        span: Span,
+        ident: Ident,
+    ) -> Stmt {
         decl_var_and_assign_stmt(
             ident.clone(),
             span,
-            self.get_custom_import_expr(module_name),
+            self.get_custom_import_expr(module_name, attributes, span),
         )
     }
 
+    fn side_effect_import_stmt(
+        &mut self,
+        module_name: String,
+        attributes: Option<Box<ObjectLit>>,
+        span: Span,
+    ) -> Stmt {
+        Stmt::Expr(ExprStmt {
+            span,
+            expr: Box::new(self.get_custom_import_expr(module_name, attributes, span)),
+        })
+    }
+
+    fn import_stmt(&mut self, import_module: ImportModule) -> Stmt {
+        let imported_name = import_module.imported_name();
+        let ImportModule {
+            span,
+            ident,
+            module_src,
+            module_type,
+            attributes,
+            ..
+        } = import_module;
+        match module_type {
+            ModuleType::Default => self.default_import_stmt(module_src, attributes, span, ident),
+            ModuleType::Named => {
+                self.named_import_stmt(module_src, attributes, span, ident, imported_name)
+            }
+            ModuleType::NamespaceOrAll => {
+                self.namespace_import_stmt(module_src, attributes, span, ident)
+            }
+            ModuleType::SideEffect => self.side_effect_import_stmt(module_src, attributes, span),
+        }
+    }
+
+    // ```js
+    // var _react = global.__modules.import("react");
+    // ```
+    //
+    // and the bindings of the imported identifiers from the module namespace.
+    // `useState` -> `_react.useState`
+    fn module_namespace_import_stmts(
+        &mut self,
+        module_src: String,
+        imports: Vec<ImportModule>,
+    ) -> (Vec<Stmt>, Vec<(Span, Ident, Expr)>) {
+        let mut import_stmts = Vec::new();
+        let mut bindings = Vec::new();
+
+        // Imports of the group share the same attributes.
+        let attributes = imports[0].attributes.clone();
+        let namespace_ident = imports
+            .iter()
+            .find(|import_module| matches!(import_module.module_type, ModuleType::NamespaceOrAll))
+            .map_or_else(
+                || module_namespace_ident(&module_src),
+                |import_module| import_module.ident.clone(),
+            );
+        if imports
+            .iter()
+            .all(|import_module| matches!(import_module.module_type, ModuleType::SideEffect))
+        {
+            import_stmts.push(self.side_effect_import_stmt(
+                module_src,
+                attributes,
+                imports[0].span,
+            ));
+            return (import_stmts, bindings);
+        }
+        import_stmts.push(self.namespace_import_stmt(
+            module_src,
+            attributes,
+            imports[0].span,
+            namespace_ident.clone(),
+        ));
+
+        // `var _react_default = __interopDefault(_react);`
+        let default_namespace_ident = if self.es_module_interop
+            && imports
+                .iter()
+                .any(|import_module| matches!(import_module.module_type, ModuleType::Default))
+        {
+            let default_namespace_ident =
+                private_ident!(format!("{}_default", namespace_ident.sym));
+            let interop_default_expr =
+                self.get_interop_default_expr(Expr::Ident(namespace_ident.clone()));
+            import_stmts.push(decl_var_and_assign_stmt(
+                default_namespace_ident.clone(),
+                DUMMY_SP,
+                interop_default_expr,
+            ));
+            default_namespace_ident
+        } else {
+            namespace_ident.clone()
+        };
+
+        for import_module in imports {
+            let binding_expr = match import_module.module_type {
+                ModuleType::Default => obj_member_expr(
+                    Expr::Ident(default_namespace_ident.clone()),
+                    ident(js_word!("default")),
+                ),
+                ModuleType::Named => obj_prop_expr(
+                    Expr::Ident(namespace_ident.clone()),
+                    import_module.imported_name(),
+                ),
+                ModuleType::NamespaceOrAll => Expr::Ident(namespace_ident.clone()),
+                ModuleType::SideEffect => continue,
+            };
+            if import_module.ident.to_id() != namespace_ident.to_id() {
+                bindings.push((import_module.span, import_module.ident, binding_expr));
+            }
+        }
+
+        (import_stmts, bindings)
+    }
+
+    // Import each module once and copy the imported bindings from the module namespace.
+    //
+    // ```js
+    // var _react = global.__modules.import("react");
+    // var React = _react.default;
+    // var useState = _react.useState;
+    // ```
+    fn cached_import_stmts(&mut self, imports: Vec<ImportModule>) -> Vec<Stmt> {
+        let mut import_stmts = Vec::new();
+
+        for (module_src, mut imports) in group_imports_by_module_src(imports) {
+            if imports.len() == 1 {
+                import_stmts.push(self.import_stmt(imports.remove(0)));
+                continue;
+            }
+            let (namespace_import_stmts, bindings) =
+                self.module_namespace_import_stmts(module_src, imports);
+            import_stmts.extend(namespace_import_stmts);
+            import_stmts.extend(bindings.into_iter().map(|(span, ident, binding_expr)| {
+                decl_var_and_assign_stmt(ident, span, binding_expr)
+            }));
+        }
+
+        import_stmts
+    }
+
+    // Import statements of the module namespaces
+    // and the bindings to replace references of the imported identifiers.
+    fn live_binding_import_stmts(
+        &mut self,
+        imports: Vec<ImportModule>,
+    ) -> (Vec<Stmt>, HashMap<Id, Expr>) {
+        let mut import_stmts = Vec::new();
+        let mut bindings = HashMap::new();
+
+        for (module_src, imports) in group_imports_by_module_src(imports) {
+            let (namespace_import_stmts, namespace_bindings) =
+                self.module_namespace_import_stmts(module_src, imports);
+            import_stmts.extend(namespace_import_stmts);
+            bindings.extend(
+                namespace_bindings
+                    .into_iter()
+                    .map(|(_, ident, binding_expr)| (ident.to_id(), binding_expr)),
+            );
+        }
+
+        (import_stmts, bindings)
+    }
+
+    // `"name": ident` or `get "name"() { return ident; }` for live exports
+    fn get_export_prop(&self, name: Atom, ident: Ident) -> PropOrSpread {
+        let key = PropName::Str(Str {
+            span: ident.span.with_ctxt(SyntaxContext::empty()),
+            value: name,
+            raw: None,
+        });
+        let value = Box::new(Expr::Ident(ident));
+
+        PropOrSpread::Prop(Box::new(if self.live_exports {
+            Prop::Getter(GetterProp {
+                span: DUMMY_SP,
+                key,
+                type_ann: None,
+                body: Some(BlockStmt {
+                    span: DUMMY_SP,
+                    stmts: vec![Stmt::Return(ReturnStmt {
+                        span: DUMMY_SP,
+                        arg: Some(value),
+                    })],
+                }),
+            })
+        } else {
+            Prop::KeyValue(KeyValueProp { key, value })
+        }))
+    }
+
     fn get_exports_obj_expr(&mut self, exports: Vec<ExportModule>) -> Expr {
-        if exports.len() == 0 {
+        if exports.is_empty() {
             return Expr::Lit(Lit::Null(Null { span: DUMMY_SP }));
         }
 
         let mut export_props = Vec::new();
+        if self.es_module_interop {
+            export_props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                key: PropName::Str(Str {
+                    span: DUMMY_SP,
+                    value: "__esModule".into(),
+                    raw: None,
+                }),
+                value: Box::new(Expr::Lit(Lit::Bool(Bool {
+                    span: DUMMY_SP,
+                    value: true,
+                }))),
+            }))));
+        }
+        let mut export_all_sources = Vec::new();
         exports.into_iter().for_each(
             |ExportModule {
                  ident,
-                 as_ident,
+                 as_name,
                  module_type,
              }| {
-                if let Some(prop_ident) = as_ident.or(Some(ident.clone())) {
+                if let Some(prop_name) = as_name.or(Some(ident.sym.clone())) {
                     export_props.push(match module_type {
-                        ModuleType::Default => {
-                            PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
-                                key: PropName::Str(Str {
-                                    span: DUMMY_SP,
-                                    value: js_word!("default"),
-                                    raw: None,
-                                }),
-                                value: Box::new(Expr::Ident(ident)),
-                            })))
-                        }
-                        ModuleType::Named => {
-                            PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
-                                key: PropName::Str(Str {
-                                    span: DUMMY_SP,
-                                    value: prop_ident.sym,
-                                    raw: None,
-                                }),
-                                value: Box::new(Expr::Ident(ident)),
-                            })))
+                        ModuleType::Default => self.get_export_prop(js_word!("default"), ident),
+                        ModuleType::Named => self.get_export_prop(prop_name, ident),
+                        // import only
+                        ModuleType::SideEffect => return,
+                        ModuleType::NamespaceOrAll => {
+                            export_all_sources.push(Some(fn_arg(Expr::Ident(ident))));
+                            return;
                         }
-                        ModuleType::NamespaceOrAll => PropOrSpread::Spread(SpreadElement {
-                            dot3_token: DUMMY_SP,
-                            expr: Box::new(Expr::Ident(ident)),
-                        }),
                     });
                 }
             },
         );
 
-        Expr::Object(ObjectLit {
+        let exports_obj = Expr::Object(ObjectLit {
             span: DUMMY_SP,
             props: export_props,
-        })
+        });
+
+        if export_all_sources.is_empty() {
+            return exports_obj;
+        }
+
+        // `__exportStar({ ... }, [__export_all, ...])`
+        let export_star_ident = self
+            .export_star_ident
+            .get_or_insert_with(|| private_ident!("__exportStar"))
+            .clone();
+        call_expr(
+            DUMMY_SP,
+            Expr::Ident(export_star_ident),
+            vec![
+                fn_arg(exports_obj),
+                fn_arg(Expr::Array(ArrayLit {
+                    span: DUMMY_SP,
+                    elems: export_all_sources,
+                })),
+            ],
+        )
     }
 
+    // Mapped to the first export of the module.
     fn get_custom_exports_stmt(&mut self, exports: Vec<ExportModule>) -> Stmt {
+        let span = exports.first().map_or(DUMMY_SP, |export_module| {
+            export_module.ident.span.with_ctxt(SyntaxContext::empty())
+        });
         let exports_obj = self.get_exports_obj_expr(exports);
         Stmt::Expr(ExprStmt {
-            span: DUMMY_SP,
-            expr: Box::new(self.get_custom_export_expr(exports_obj)),
+            span,
+            expr: Box::new(self.get_custom_export_expr(exports_obj, span)),
         })
     }
+
+    // Comments of the exports without statements (eg. `// Re-exported` of `export { a };`)
+    // are moved to the exports statement, which takes the span of the first one.
+    fn attach_export_comments(&self, exports_stmt: &mut Stmt, export_comment_spans: Vec<Span>) {
+        let (comments, first_span) = match (&self.comments, export_comment_spans.first()) {
+            (Some(comments), Some(first_span)) => (comments, *first_span),
+            _ => return,
+        };
+        for span in &export_comment_spans[1..] {
+            comments.move_leading(span.lo, first_span.lo);
+        }
+        if let Stmt::Expr(ExprStmt { span, expr }) = exports_stmt {
+            *span = first_span;
+            if let Expr::Call(call_expr) = &mut **expr {
+                call_expr.span = first_span;
+            }
+        }
+    }
 }
 
 impl VisitMut for ReactNativeEsbuildModule {
     noop_visit_mut_type!();
 
     fn visit_mut_module(&mut self, module: &mut Module) {
-        let mut collector = ModuleCollector::default(self.runtime_module);
+        // The prelude module is evaluated before the other modules to provide the module registry.
+        let is_prelude = self.is_prelude_module();
+
+        let mut collector =
+            ModuleCollector::default(self.runtime_module, self.strict, self.comments.as_deref());
         module.visit_mut_with(&mut collector);
 
         let ModuleCollector {
-            imports, exports, ..
+            mut imports,
+            exports,
+            export_assignment,
+            export_assignment_span,
+            export_comment_spans,
+            ..
         } = collector;
-        let is_esm = imports.len() + exports.len() > 0;
 
-        // Imports
-        imports.into_iter().enumerate().for_each(
-            |(
-                index,
-                ImportModule {
-                    span,
-                    ident,
-                    module_src,
-                    module_type,
-                },
-            )| match module_type {
-                ModuleType::Default => {
-                    module.body.insert(
-                        index,
-                        self.default_import_stmt(module_src, span, ident).into(),
-                    );
-                }
-                ModuleType::Named => {
-                    module.body.insert(
-                        index,
-                        self.named_import_stmt(module_src, span, ident).into(),
-                    );
-                }
-                ModuleType::NamespaceOrAll => {
-                    module.body.insert(
-                        index,
-                        self.namespace_import_stmt(module_src, span, ident).into(),
-                    );
+        // `import data from './data.json' with { type: 'json' };` to `var data = { ... };`
+        let mut json_import_stmts = Vec::new();
+        if self.inline_json {
+            imports.retain(|import_module| {
+                let json_expr = match (&import_module.module_type, &import_module.attributes) {
+                    (ModuleType::Default | ModuleType::NamespaceOrAll, Some(attributes))
+                        if is_json_attributes(attributes) =>
+                    {
+                        load_json_module(&self.module_name, &import_module.module_src)
+                    }
+                    _ => None,
+                };
+                let json_expr = match json_expr {
+                    Some(json_expr) => json_expr,
+                    None => return true,
+                };
+                json_import_stmts.push(decl_var_and_assign_stmt(
+                    import_module.ident.clone(),
+                    import_module.span,
+                    match import_module.module_type {
+                        // `{ default: { ... } }`
+                        ModuleType::NamespaceOrAll => Expr::Object(ObjectLit {
+                            span: DUMMY_SP,
+                            props: vec![PropOrSpread::Prop(Box::new(Prop::KeyValue(
+                                KeyValueProp {
+                                    key: PropName::Ident(ident(js_word!("default"))),
+                                    value: Box::new(json_expr),
+                                },
+                            )))],
+                        }),
+                        _ => json_expr,
+                    },
+                ));
+                false
+            });
+        }
+        let is_esm = imports.len() + exports.len() + json_import_stmts.len() > 0
+            || export_assignment.is_some();
+        let mut deps: Vec<String> = Vec::new();
+        imports.iter().for_each(|import_module| {
+            if !deps.contains(&import_module.module_src) {
+                deps.push(import_module.module_src.clone());
+            }
+        });
+
+        // `import.meta.hot` and `module.hot` to the HMR context of the module
+        let mut hot_context = None;
+        if self.hmr {
+            let mut replacer =
+                HotContextReplacer::new(private_ident!("__hot"), self.unresolved_ctxt);
+            module.visit_mut_with(&mut replacer);
+            hot_context = Some((replacer.hot_ident, replacer.is_referenced));
+        }
+
+        // CommonJS
+        let mut commonjs_collector = None;
+        if self.commonjs {
+            let mut collector =
+                CommonJsCollector::new(self.unresolved_ctxt, self.runtime_module && !is_esm);
+            module.visit_mut_with(&mut collector);
+            collector.requires.iter().for_each(|module_src| {
+                if !deps.contains(module_src) {
+                    deps.push(module_src.clone());
                 }
-            },
-        );
+            });
+            if !is_esm && collector.is_cjs() {
+                commonjs_collector = Some(collector);
+            } else if let (true, Some(module_ref_span)) = (is_esm, collector.module_ref_span) {
+                report_unsupported(
+                    module_ref_span,
+                    "`module` and `exports` of ES modules are not registered",
+                    self.strict,
+                );
+            }
+        }
+        let is_cjs = commonjs_collector.is_some();
+        // Every module is registered in runtime mode to be imported for its side effects.
+        let is_registered = is_esm || is_cjs || self.runtime_module;
+
+        // Dynamic imports and `require()`
+        if self.runtime_module {
+            module.visit_mut_children_with(self);
+        }
 
         // Exports
-        if is_esm {
+        // Live exports are registered at the top of the module (before imports)
+        // to be available to the circular imports.
+        let prologue_len = directive_prologue_len(&module.body);
+        let mut exports_stmt_len = 0;
+        if let Some(export_assignment) = export_assignment {
+            // `export = ...` replaces the whole exports of the module.
+            module.body.push(
+                Stmt::Expr(ExprStmt {
+                    span: export_assignment_span,
+                    expr: Box::new(self.get_custom_export_expr(
+                        Expr::Ident(export_assignment),
+                        export_assignment_span,
+                    )),
+                })
+                .into(),
+            );
+        } else if is_esm {
+            // `export * from '...'` reads the source modules, so it's registered after imports.
+            let has_export_all = exports.iter().any(|export_module| {
+                matches!(export_module.module_type, ModuleType::NamespaceOrAll)
+            });
+            let mut exports_stmt = self.get_custom_exports_stmt(exports);
+            self.attach_export_comments(&mut exports_stmt, export_comment_spans);
+            let exports_stmt = exports_stmt.into();
+            if self.live_exports {
+                module.body.insert(prologue_len, exports_stmt);
+                if !has_export_all {
+                    exports_stmt_len = 1;
+                }
+            } else {
+                module.body.push(exports_stmt);
+            }
+        } else if let Some(CommonJsCollector {
+            module_ident,
+            exports_ident,
+            module_ref_span,
+            ..
+        }) = commonjs_collector
+        {
+            if self.runtime_module {
+                // ```js
+                // var __module = { exports: {} };
+                // var __exports = __module.exports;
+                // ```
+                module.body.splice(
+                    prologue_len..prologue_len,
+                    [
+                        decl_var_and_assign_stmt(
+                            module_ident.clone(),
+                            DUMMY_SP,
+                            Expr::Object(ObjectLit {
+                                span: DUMMY_SP,
+                                props: vec![PropOrSpread::Prop(Box::new(Prop::KeyValue(
+                                    KeyValueProp {
+                                        key: PropName::Ident(ident(js_word!("exports"))),
+                                        value: Box::new(Expr::Object(ObjectLit {
+                                            span: DUMMY_SP,
+                                            props: Vec::new(),
+                                        })),
+                                    },
+                                )))],
+                            }),
+                        )
+                        .into(),
+                        decl_var_and_assign_stmt(
+                            exports_ident,
+                            DUMMY_SP,
+                            obj_member_expr(
+                                Expr::Ident(module_ident.clone()),
+                                ident(js_word!("exports")),
+                            ),
+                        )
+                        .into(),
+                    ],
+                );
+            }
+            let module_ident = if self.runtime_module {
+                module_ident
+            } else {
+                Ident::new(js_word!("module"), DUMMY_SP.with_ctxt(self.unresolved_ctxt))
+            };
+            let module_exports_expr =
+                obj_member_expr(Expr::Ident(module_ident), ident(js_word!("exports")));
+            // Mapped to the first `module` / `exports` reference.
+            let span =
+                module_ref_span.map_or(DUMMY_SP, |span| span.with_ctxt(SyntaxContext::empty()));
+            module.body.push(
+                Stmt::Expr(ExprStmt {
+                    span,
+                    expr: Box::new(self.get_custom_export_expr(module_exports_expr, span)),
+                })
+                .into(),
+            );
+        } else if is_registered {
+            // `import './polyfill';`
             module
                 .body
-                .push(self.get_custom_exports_stmt(exports).into());
+                .push(self.get_custom_exports_stmt(Vec::new()).into());
+        }
+
+        // Imports
+        let import_stmts: Vec<Stmt> = if self.live_bindings {
+            let (mut import_stmts, bindings) = self.live_binding_import_stmts(imports);
+            let mut replacer = LiveBindingReplacer::new(bindings, self.strict);
+            module.visit_mut_with(&mut replacer);
+            import_stmts.extend(replacer.jsx_alias_bindings().into_iter().map(
+                |(ident, binding_expr)| decl_var_and_assign_stmt(ident, DUMMY_SP, binding_expr),
+            ));
+            import_stmts
+        } else {
+            self.cached_import_stmts(imports)
+        };
+        module.body.splice(
+            prologue_len + exports_stmt_len..prologue_len + exports_stmt_len,
+            json_import_stmts
+                .into_iter()
+                .chain(import_stmts)
+                .map(ModuleItem::from),
+        );
+
+        // Re-export names of the source modules except `default`.
+        // Explicit exports take precedence and ambiguous names (exported by multiple sources) are dropped.
+        if let Some(export_star_ident) = self.export_star_ident.take() {
+            module.body.insert(
+                prologue_len,
+                quote!(
+                    "var $export_star = function (exports, sources) {
+                        var hasOwn = Object.prototype.hasOwnProperty;
+                        var names = {};
+                        sources.forEach(function (source) {
+                            source && Object.keys(source).forEach(function (name) {
+                                if (name === 'default' || name === '__esModule' || hasOwn.call(exports, name)) {
+                                    return;
+                                }
+                                if (hasOwn.call(names, name)) {
+                                    if (names[name] && names[name][name] !== source[name]) {
+                                        names[name] = null;
+                                    }
+                                    return;
+                                }
+                                names[name] = source;
+                            });
+                        });
+                        Object.keys(names).forEach(function (name) {
+                            var source = names[name];
+                            source && Object.defineProperty(exports, name, {
+                                enumerable: true,
+                                get: function () {
+                                    return source[name];
+                                },
+                            });
+                        });
+                        return exports;
+                    };" as ModuleItem,
+                    export_star = export_star_ident,
+                ),
+            );
+        }
+
+        // ```js
+        // var __interopDefault = function (m) {
+        //   return m && m.__esModule ? m : { default: m };
+        // };
+        // ```
+        if let Some(interop_default_ident) = self.interop_default_ident.take() {
+            module.body.insert(
+                prologue_len,
+                quote!(
+                    "var $interop_default = function (m) {
+                        return m && m.__esModule ? m : { default: m };
+                    };" as ModuleItem,
+                    interop_default = interop_default_ident,
+                ),
+            );
+        }
+
+        // ```js
+        // var __hot = global.__modules.hot("id");
+        // ```
+        if let Some((hot_ident, is_referenced)) = hot_context {
+            if is_registered || is_referenced {
+                let module_id_expr = self.get_export_module_id_expr(DUMMY_SP);
+                let hot_context_expr = call_expr(
+                    DUMMY_SP,
+                    self.get_registry_method_expr(RegistryMethod::Hot),
+                    vec![fn_arg(module_id_expr)],
+                );
+                module.body.insert(
+                    prologue_len,
+                    decl_var_and_assign_stmt(hot_ident, DUMMY_SP, hot_context_expr).into(),
+                );
+            }
+        }
+
+        // Wrap module code with factory
+        // Module declarations are remained only when `runtime_module` is `false`.
+        // The prelude is not wrapped because the registry must be available immediately.
+        if self.factory && self.runtime_module && is_registered && !is_prelude {
+            // Top-level `await` is not valid in the (non-async) factory, so the module is kept as is.
+            if let Some(module_item) = module
+                .body
+                .iter()
+                .find(|module_item| contains_top_level_await(*module_item))
+            {
+                report_unsupported(
+                    module_item.span(),
+                    "top-level `await` is not supported in module factories, the module is not wrapped",
+                    self.strict,
+                );
+            } else {
+                let body = module
+                    .body
+                    .drain(..)
+                    .filter_map(|module_item| module_item.stmt())
+                    .collect();
+                module.body = vec![self.get_custom_define_stmt(deps, body).into()];
+            }
+        }
+
+        let prologue_len = directive_prologue_len(&module.body);
+        if is_prelude {
+            let runtime_stmt = self.get_runtime_stmt();
+            module.body.insert(prologue_len, runtime_stmt.into());
+        }
+        if let Some(global_stmt) = self.take_global_stmt() {
+            module.body.insert(prologue_len, global_stmt);
+        }
+    }
+
+    fn visit_mut_script(&mut self, script: &mut Script) {
+        let mut module = Module {
+            span: script.span,
+            body: script.body.drain(..).map(ModuleItem::from).collect(),
+            shebang: script.shebang.take(),
+        };
+        self.visit_mut_module(&mut module);
+        script.body = module
+            .body
+            .into_iter()
+            .filter_map(|module_item| module_item.stmt())
+            .collect();
+        script.shebang = module.shebang;
+    }
+
+    // `import('./screen')` to `global.__modules.importAsync("./screen")`
+    // `require('./module')` to `global.__modules.import("./module")`
+    fn visit_mut_call_expr(&mut self, call_expr: &mut CallExpr) {
+        call_expr.visit_mut_children_with(self);
+
+        if self.commonjs {
+            if let Some(module_src) = get_require_module_src(call_expr, self.unresolved_ctxt) {
+                call_expr.args[0] =
+                    fn_arg(self.get_import_module_id_expr(&module_src.value, module_src.span));
+                call_expr.callee = Callee::Expr(Box::new(
+                    self.get_registry_method_expr(RegistryMethod::Import),
+                ));
+                return;
+            }
+            if matches!(
+                &call_expr.callee,
+                Callee::Expr(callee) if matches!(
+                    &**callee,
+                    Expr::Ident(callee_ident)
+                        if &*callee_ident.sym == "require"
+                            && callee_ident.span.ctxt == self.unresolved_ctxt
+                )
+            ) {
+                report_unsupported(
+                    call_expr.span,
+                    "`require()` without a string literal is not transformed",
+                    self.strict,
+                );
+                return;
+            }
+        }
+
+        if let CallExpr {
+            callee: Callee::Import(_),
+            args,
+            ..
+        } = call_expr
+        {
+            if let Some(Expr::Lit(Lit::Str(module_src))) =
+                args.first().map(|module_src_arg| &*module_src_arg.expr)
+            {
+                debug!("dynamic import: {:#?}", module_src.value);
+                args[0] =
+                    fn_arg(self.get_import_module_id_expr(&module_src.value, module_src.span));
+                call_expr.callee = Callee::Expr(Box::new(
+                    self.get_registry_method_expr(RegistryMethod::ImportAsync),
+                ));
+            } else {
+                report_unsupported(
+                    call_expr.span,
+                    "`import()` without a string literal is not transformed",
+                    self.strict,
+                );
+            }
+        }
+    }
+}
+
+/// Parse the plugin configuration.
+///
+/// Empty configuration (`""` or `null`) falls back to the defaults,
+/// and the error message contains the offending option if it's invalid.
+fn parse_options(config: &str) -> Result<ReactNativeEsbuildModuleOptions, String> {
+    if config.trim().is_empty() {
+        return Ok(Default::default());
+    }
+
+    let options = match serde_json::from_str::<serde_json::Value>(config) {
+        Ok(serde_json::Value::Null) => return Ok(Default::default()),
+        Ok(serde_json::Value::Object(options)) => options,
+        Ok(value) => return Err(format!("expected an object, found `{}`", value)),
+        Err(error) => return Err(format!("invalid JSON: {}", error)),
+    };
+
+    // Deserialize options one by one to report which option is invalid.
+    for (key, value) in options.iter() {
+        let option = serde_json::Map::from_iter([(key.clone(), value.clone())]);
+        if let Err(error) = serde_json::from_value::<ReactNativeEsbuildModuleOptions>(option.into())
+        {
+            return Err(format!("`{}`: {}", key, error));
+        }
+    }
+
+    let options: ReactNativeEsbuildModuleOptions =
+        serde_json::from_value(options.into()).map_err(|error| error.to_string())?;
+    if let Some(global_object) = &options.global_object {
+        if !is_valid_prop_ident(global_object) {
+            return Err(format!(
+                "`globalObject`: expected an identifier, found {:?}",
+                global_object
+            ));
         }
     }
+    Ok(options)
 }
 
 #[plugin_transform]
@@ -204,21 +1258,26 @@ pub fn react_native_esbuild_module_plugin(
     program: Program,
     metadata: TransformPluginProgramMetadata,
 ) -> Program {
-    let config = serde_json::from_str::<ReactNativeEsbuildModuleOptions>(
-        &metadata
-            .get_transform_plugin_config()
-            .expect("failed to get plugin config for swc-plugin-react-native-esbuild-module"),
-    )
-    .expect("invalid config for swc-plugin-react-native-esbuild-module");
+    let config = match parse_options(&metadata.get_transform_plugin_config().unwrap_or_default()) {
+        Ok(config) => config,
+        Err(message) => {
+            HANDLER.with(|handler| {
+                handler.err(&format!("{}: invalid config: {}", PLUGIN_NAME, message))
+            });
+            return program;
+        }
+    };
 
     let filename = metadata
         .get_context(&TransformPluginMetadataContextKind::Filename)
         .unwrap_or_default();
 
-    program.fold_with(&mut as_folder(ReactNativeEsbuildModule {
-        module_name: filename,
-        runtime_module: config.runtime_module.unwrap_or(false),
-    }))
+    let mut plugin = ReactNativeEsbuildModule::new(filename, metadata.unresolved_mark, config);
+    if let Some(comments) = metadata.comments {
+        plugin = plugin.with_comments(comments);
+    }
+
+    program.fold_with(&mut as_folder(plugin))
 }
 
 #[cfg(test)]
@@ -232,3 +1291,71 @@ mod esm_export;
 #[cfg(test)]
 #[path = "./tests/bundle_time_module.rs"]
 mod bundle_time_module;
+
+#[cfg(test)]
+#[path = "./tests/module_resolution.rs"]
+mod module_resolution;
+
+#[cfg(test)]
+#[path = "./tests/esm_live_binding.rs"]
+mod esm_live_binding;
+
+#[cfg(test)]
+#[path = "./tests/esm_live_export.rs"]
+mod esm_live_export;
+
+#[cfg(test)]
+#[path = "./tests/module_factory.rs"]
+mod module_factory;
+
+#[cfg(test)]
+#[path = "./tests/cjs.rs"]
+mod cjs;
+
+#[cfg(test)]
+#[path = "./tests/esm_interop.rs"]
+mod esm_interop;
+
+#[cfg(test)]
+#[path = "./tests/ts_module.rs"]
+mod ts_module;
+
+#[cfg(test)]
+#[path = "./tests/import_attributes.rs"]
+mod import_attributes;
+
+#[cfg(test)]
+#[path = "./tests/options.rs"]
+mod options;
+
+#[cfg(test)]
+#[path = "./tests/unsupported_syntax.rs"]
+mod unsupported_syntax;
+
+#[cfg(test)]
+#[path = "./tests/registry.rs"]
+mod registry;
+
+#[cfg(test)]
+#[path = "./tests/runtime.rs"]
+mod runtime;
+
+#[cfg(test)]
+#[path = "./tests/source_map.rs"]
+mod source_map;
+
+#[cfg(test)]
+#[path = "./tests/comments.rs"]
+mod comments;
+
+#[cfg(test)]
+#[path = "./tests/hot_module_replacement.rs"]
+mod hot_module_replacement;
+
+#[cfg(test)]
+#[path = "./tests/evaluation.rs"]
+mod evaluation;
+
+#[cfg(test)]
+#[path = "./tests/harness.rs"]
+mod harness;
@@ -0,0 +1,286 @@
+use crate::diagnostics::report_unsupported;
+use crate::utils::{num_lit_expr, str_lit_expr};
+use std::collections::HashMap;
+use swc_core::{
+    common::{util::take::Take, DUMMY_SP},
+    ecma::{
+        ast::*,
+        utils::{find_pat_ids, private_ident},
+        visit::{noop_visit_mut_type, VisitMut, VisitMutWith},
+    },
+    quote,
+};
+use tracing::debug;
+
+/// Replace references of the imported bindings with the member expression of the module namespace.
+///
+/// ```js
+/// useState(0);
+/// // to
+/// (0, _react.useState)(0);
+/// ```
+pub struct LiveBindingReplacer {
+    bindings: HashMap<Id, Expr>,
+    // Bindings referenced by the JSX element names that can't be the member expression.
+    // eg. `_components["x-y"]` for `import { "x-y" as Xy } from '@app/components';`
+    jsx_aliases: Vec<Ident>,
+    strict: bool,
+}
+
+impl LiveBindingReplacer {
+    pub fn new(bindings: HashMap<Id, Expr>, strict: bool) -> Self {
+        LiveBindingReplacer {
+            bindings,
+            jsx_aliases: Vec::new(),
+            strict,
+        }
+    }
+
+    // Local aliases of the bindings that are kept in the JSX element names.
+    //
+    // ```js
+    // var Xy = _components["x-y"];
+    // ```
+    pub fn jsx_alias_bindings(&self) -> Vec<(Ident, Expr)> {
+        self.jsx_aliases
+            .iter()
+            .filter_map(|ident| {
+                self.get_binding_expr(ident)
+                    .map(|binding_expr| (ident.clone(), binding_expr))
+            })
+            .collect()
+    }
+
+    fn add_jsx_alias(&mut self, ident: &Ident) {
+        if self.bindings.contains_key(&ident.to_id())
+            && !self
+                .jsx_aliases
+                .iter()
+                .any(|alias| alias.to_id() == ident.to_id())
+        {
+            debug!("jsx alias: {:#?}", ident.sym);
+            self.jsx_aliases.push(ident.clone());
+        }
+    }
+
+    fn get_binding_expr(&self, ident: &Ident) -> Option<Expr> {
+        self.bindings.get(&ident.to_id()).cloned()
+    }
+
+    // The imported binding of the assignment target.
+    // `a = 1`, `[a] = arr`, `({ a } = obj)`
+    fn get_assigned_binding(&self, target: &PatOrExpr) -> Option<Ident> {
+        let idents = match target {
+            PatOrExpr::Expr(expr) => expr.as_ident().cloned().into_iter().collect(),
+            PatOrExpr::Pat(pat) => match &**pat {
+                Pat::Expr(expr) => expr.as_ident().cloned().into_iter().collect(),
+                _ => find_pat_ids::<_, Ident>(pat),
+            },
+        };
+        idents
+            .into_iter()
+            .find(|ident| self.bindings.contains_key(&ident.to_id()))
+    }
+
+    // Imported bindings are read-only, assignments throw like ES modules
+    // instead of writing to the exports of the imported module.
+    //
+    // ```js
+    // a = value;
+    // // to
+    // value, function () { throw new TypeError('"a" is read-only'); }();
+    // ```
+    // `for (a of values) { ... }` to `for (var _a of values) { throw ...; ... }`
+    fn replace_assigned_for_head(&self, for_head: &mut ForHead, body: &mut Stmt) {
+        let ident = match for_head {
+            ForHead::Pat(pat) => self.get_assigned_binding(&PatOrExpr::Pat(pat.clone())),
+            _ => None,
+        };
+        if let Some(ident) = ident {
+            *for_head = ForHead::VarDecl(Box::new(VarDecl {
+                span: DUMMY_SP,
+                kind: VarDeclKind::Var,
+                declare: false,
+                decls: vec![VarDeclarator {
+                    span: DUMMY_SP,
+                    name: Pat::Ident(private_ident!(ident.sym.clone()).into()),
+                    init: None,
+                    definite: false,
+                }],
+            }));
+            *body = Stmt::Block(BlockStmt {
+                span: DUMMY_SP,
+                stmts: vec![
+                    Stmt::Expr(ExprStmt {
+                        span: DUMMY_SP,
+                        expr: Box::new(self.read_only_error_expr(&ident, None)),
+                    }),
+                    body.take(),
+                ],
+            });
+        }
+    }
+
+    fn read_only_error_expr(&self, ident: &Ident, value: Option<Box<Expr>>) -> Expr {
+        report_unsupported(
+            ident.span,
+            &format!(
+                "`{}` is an imported binding, assigning to it throws a `TypeError`",
+                ident.sym
+            ),
+            self.strict,
+        );
+        let throw_expr = quote!(
+            "(function () { throw new TypeError($message); })()" as Expr,
+            message: Expr = str_lit_expr(format!("\"{}\" is read-only", ident.sym)),
+        );
+        match value {
+            Some(value) => Expr::Seq(SeqExpr {
+                span: DUMMY_SP,
+                exprs: vec![value, Box::new(throw_expr)],
+            }),
+            None => throw_expr,
+        }
+    }
+
+    // `<Button />` to `<_components.Button />`
+    fn get_binding_jsx_element_name(&self, ident: &Ident) -> Option<JSXElementName> {
+        match self.get_binding_expr(ident) {
+            Some(Expr::Member(MemberExpr {
+                obj,
+                prop: MemberProp::Ident(prop),
+                ..
+            })) => match *obj {
+                Expr::Ident(obj) => Some(JSXElementName::JSXMemberExpr(JSXMemberExpr {
+                    obj: JSXObject::Ident(obj),
+                    prop,
+                })),
+                _ => None,
+            },
+            Some(Expr::Ident(binding_ident)) => Some(JSXElementName::Ident(binding_ident)),
+            _ => None,
+        }
+    }
+
+    // Calls the member expression without `this` binding.
+    // `(0, _react.useState)`
+    fn get_unbound_callee(&self, expr: &Expr) -> Option<Box<Expr>> {
+        match expr {
+            Expr::Ident(ident) => match self.get_binding_expr(ident) {
+                Some(binding_expr @ Expr::Member(_)) => Some(Box::new(Expr::Paren(ParenExpr {
+                    span: DUMMY_SP,
+                    expr: Box::new(Expr::Seq(SeqExpr {
+                        span: DUMMY_SP,
+                        exprs: vec![Box::new(num_lit_expr(0.0)), Box::new(binding_expr)],
+                    })),
+                }))),
+                Some(binding_expr) => Some(Box::new(binding_expr)),
+                None => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+impl VisitMut for LiveBindingReplacer {
+    noop_visit_mut_type!();
+
+    fn visit_mut_expr(&mut self, expr: &mut Expr) {
+        match expr {
+            Expr::Ident(ident) => {
+                if let Some(binding_expr) = self.get_binding_expr(ident) {
+                    debug!("live binding: {:#?}", ident.sym);
+                    *expr = binding_expr;
+                }
+                return;
+            }
+            // `a++`
+            Expr::Update(UpdateExpr { arg, .. }) => {
+                if let Some(ident) = arg
+                    .as_ident()
+                    .filter(|ident| self.bindings.contains_key(&ident.to_id()))
+                {
+                    *expr = self.read_only_error_expr(ident, None);
+                    return;
+                }
+            }
+            // `a = 1`, `a += 1`, `({ a } = obj)`
+            Expr::Assign(AssignExpr { left, right, .. }) => {
+                if let Some(ident) = self.get_assigned_binding(left) {
+                    right.visit_mut_with(self);
+                    *expr = self.read_only_error_expr(&ident, Some(right.take()));
+                    return;
+                }
+            }
+            _ => {}
+        }
+        expr.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_for_in_stmt(&mut self, for_in_stmt: &mut ForInStmt) {
+        self.replace_assigned_for_head(&mut for_in_stmt.left, &mut for_in_stmt.body);
+        for_in_stmt.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_for_of_stmt(&mut self, for_of_stmt: &mut ForOfStmt) {
+        self.replace_assigned_for_head(&mut for_of_stmt.left, &mut for_of_stmt.body);
+        for_of_stmt.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_callee(&mut self, callee: &mut Callee) {
+        if let Callee::Expr(callee_expr) = callee {
+            if let Some(unbound_callee) = self.get_unbound_callee(callee_expr) {
+                *callee_expr = unbound_callee;
+                return;
+            }
+        }
+        callee.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_tagged_tpl(&mut self, tagged_tpl: &mut TaggedTpl) {
+        if let Some(unbound_tag) = self.get_unbound_callee(&tagged_tpl.tag) {
+            tagged_tpl.tag = unbound_tag;
+        } else {
+            tagged_tpl.tag.visit_mut_with(self);
+        }
+        tagged_tpl.tpl.visit_mut_with(self);
+    }
+
+    // `{ useState }` to `{ useState: _react.useState }`
+    fn visit_mut_prop(&mut self, prop: &mut Prop) {
+        if let Prop::Shorthand(ident) = prop {
+            if let Some(binding_expr) = self.get_binding_expr(ident) {
+                *prop = Prop::KeyValue(KeyValueProp {
+                    key: PropName::Ident(ident.clone()),
+                    value: Box::new(binding_expr),
+                });
+            }
+            return;
+        }
+        prop.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_jsx_element_name(&mut self, jsx_element_name: &mut JSXElementName) {
+        if let JSXElementName::Ident(ident) = jsx_element_name {
+            match self.get_binding_jsx_element_name(ident) {
+                Some(binding_jsx_element_name) => *jsx_element_name = binding_jsx_element_name,
+                None => self.add_jsx_alias(ident),
+            }
+            return;
+        }
+        jsx_element_name.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_jsx_object(&mut self, jsx_object: &mut JSXObject) {
+        match jsx_object {
+            JSXObject::Ident(ident) => match self.get_binding_jsx_element_name(ident) {
+                Some(JSXElementName::JSXMemberExpr(jsx_member_expr)) => {
+                    *jsx_object = JSXObject::JSXMemberExpr(Box::new(jsx_member_expr));
+                }
+                Some(_) => {}
+                None => self.add_jsx_alias(ident),
+            },
+            JSXObject::JSXMemberExpr(jsx_member_expr) => jsx_member_expr.obj.visit_mut_with(self),
+        }
+    }
+}
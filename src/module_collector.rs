@@ -1,9 +1,13 @@
-use crate::utils::decl_var_and_assign_stmt;
+use crate::diagnostics::report_unsupported;
+use crate::utils::{
+    decl_var_and_assign_stmt, module_export_name, module_namespace_ident, ts_entity_name_expr,
+};
 use swc_core::{
-    common::{Span, DUMMY_SP},
+    atoms::Atom,
+    common::{comments::Comments, BytePos, Span, Spanned, DUMMY_SP},
     ecma::{
         ast::*,
-        utils::private_ident,
+        utils::{find_pat_ids, private_ident},
         visit::{VisitMut, VisitMutWith},
     },
 };
@@ -16,14 +20,30 @@ pub enum ModuleType {
     // import: namespace
     // export: all
     NamespaceOrAll,
+    // import only: `import 'module';`
+    SideEffect,
 }
 
 #[derive(Debug)]
 pub struct ImportModule {
     pub span: Span,
+    // `b` in `import { a as b } from '...';`
     pub ident: Ident,
+    // `a` in `import { a as b } from '...';`
+    pub imported: Option<ModuleExportName>,
     pub module_src: String,
     pub module_type: ModuleType,
+    // `{ type: 'json' }` in `import a from '...' with { type: 'json' };`
+    pub attributes: Option<Box<ObjectLit>>,
+}
+
+impl ImportModule {
+    // `a` in `import { a as b } from '...';`
+    pub fn imported_name(&self) -> Atom {
+        self.imported
+            .as_ref()
+            .map_or(self.ident.sym.clone(), module_export_name)
+    }
 }
 
 #[derive(Debug)]
@@ -31,7 +51,7 @@ pub struct ExportModule {
     // `a` in `export { a as a_1 };`
     pub ident: Ident,
     // `a_1` in `export { a as a_1 };`
-    pub as_ident: Option<Ident>,
+    pub as_name: Option<Atom>,
     pub module_type: ModuleType,
 }
 
@@ -39,38 +59,62 @@ impl ExportModule {
     fn default(ident: Ident) -> Self {
         ExportModule {
             ident,
-            as_ident: None,
+            as_name: None,
             module_type: ModuleType::Default,
         }
     }
 
-    fn named(ident: Ident, as_ident: Option<Ident>) -> Self {
+    fn named(ident: Ident, as_name: Option<Atom>) -> Self {
         ExportModule {
             ident,
-            as_ident,
+            as_name,
             module_type: ModuleType::Named,
         }
     }
 }
 
-pub struct ModuleCollector {
+pub struct ModuleCollector<'a> {
     pub imports: Vec<ImportModule>,
     pub exports: Vec<ExportModule>,
+    // `__export_assignment` in `export = expr;`
+    pub export_assignment: Option<Ident>,
+    pub export_assignment_span: Span,
+    // Spans of the commented exports that are not transformed to any statement (eg. `export { a };`).
+    pub export_comment_spans: Vec<Span>,
     runtime_module: bool,
+    strict: bool,
+    comments: Option<&'a dyn Comments>,
 }
 
-impl ModuleCollector {
-    pub fn default(runtime_module: bool) -> Self {
+impl<'a> ModuleCollector<'a> {
+    pub fn default(runtime_module: bool, strict: bool, comments: Option<&'a dyn Comments>) -> Self {
         ModuleCollector {
             runtime_module,
+            strict,
+            comments,
             imports: Vec::new(),
             exports: Vec::new(),
+            export_assignment: None,
+            export_assignment_span: DUMMY_SP,
+            export_comment_spans: Vec::new(),
         }
     }
 
-    fn get_export_decl_stmt_with_private_ident(&mut self, expr: Expr) -> (Ident, Stmt) {
+    // Keep the comments of the original module item (eg. `// @ts-ignore`) on the transformed statement.
+    //
+    // Comments at the beginning of the module (eg. license headers, `@flow` pragmas) are kept in place
+    // to be emitted before the statements that are inserted at the top of the module.
+    fn move_leading_comments(&self, module_pos: BytePos, from: BytePos, to: BytePos) {
+        if let Some(comments) = self.comments {
+            if from != module_pos && from != to {
+                comments.move_leading(from, to);
+            }
+        }
+    }
+
+    fn get_export_decl_stmt_with_private_ident(&mut self, expr: Expr, span: Span) -> (Ident, Stmt) {
         let export_ident: Ident = private_ident!("__export_default");
-        let stmt = decl_var_and_assign_stmt(export_ident.clone(), DUMMY_SP, expr);
+        let stmt = decl_var_and_assign_stmt(export_ident.clone(), span, expr);
         (export_ident, stmt)
     }
 
@@ -81,6 +125,22 @@ impl ModuleCollector {
         })
     }
 
+    fn collect_export_assignment_and_convert_to_stmt(
+        &mut self,
+        ts_export_assignment: &TsExportAssignment,
+    ) -> (Ident, Stmt) {
+        debug!("export assignment");
+        let export_ident: Ident = private_ident!("__export_assignment");
+        let stmt = decl_var_and_assign_stmt(
+            export_ident.clone(),
+            ts_export_assignment.span,
+            *ts_export_assignment.expr.clone(),
+        );
+        self.export_assignment = Some(export_ident.clone());
+        self.export_assignment_span = ts_export_assignment.span;
+        (export_ident, stmt)
+    }
+
     fn collect_default_export_decl_and_convert_to_stmt(
         &mut self,
         export_default_decl: &ExportDefaultDecl,
@@ -104,8 +164,10 @@ impl ModuleCollector {
             }
             DefaultDecl::Fn(fn_expr) => {
                 debug!("default export decl fn: <anonymous>");
-                let (ident, stmt) =
-                    self.get_export_decl_stmt_with_private_ident(Expr::Fn(fn_expr.to_owned()));
+                let (ident, stmt) = self.get_export_decl_stmt_with_private_ident(
+                    Expr::Fn(fn_expr.to_owned()),
+                    export_default_decl.span,
+                );
                 self.exports.push(ExportModule::default(ident.clone()));
                 Some((ident, stmt))
             }
@@ -128,12 +190,15 @@ impl ModuleCollector {
             }
             DefaultDecl::Class(class_expr) => {
                 debug!("default export decl class: <anonymous>");
-                let (ident, stmt) = self
-                    .get_export_decl_stmt_with_private_ident(Expr::Class(class_expr.to_owned()));
+                let (ident, stmt) = self.get_export_decl_stmt_with_private_ident(
+                    Expr::Class(class_expr.to_owned()),
+                    export_default_decl.span,
+                );
                 self.exports.push(ExportModule::default(ident.clone()));
                 Some((ident, stmt))
             }
-            _ => None,
+            // `export default interface ...` (types only)
+            DefaultDecl::TsInterfaceDecl(_) => None,
         }
     }
 
@@ -141,22 +206,31 @@ impl ModuleCollector {
         &mut self,
         export_default_expr: &ExportDefaultExpr,
     ) -> (Ident, Stmt) {
-        let (ident, stmt) =
-            self.get_export_decl_stmt_with_private_ident(*export_default_expr.expr.to_owned());
+        let (ident, stmt) = self.get_export_decl_stmt_with_private_ident(
+            *export_default_expr.expr.to_owned(),
+            export_default_expr.span,
+        );
         self.exports.push(ExportModule::default(ident.clone()));
         (ident, stmt)
     }
 }
 
-impl VisitMut for ModuleCollector {
+impl VisitMut for ModuleCollector<'_> {
     fn visit_mut_module(&mut self, module: &mut Module) {
         let mut module_body = Vec::with_capacity(module.body.len());
         for module_item in module.body.drain(..) {
+            let module_body_len = module_body.len();
+            let imports_len = self.imports.len();
+            let exports_len = self.exports.len();
+            let module_item_span = module_item.span();
             match module_item {
                 ModuleItem::Stmt(stmt) => module_body.push(stmt.into()),
                 ModuleItem::ModuleDecl(mut module_decl) => match &module_decl {
                     // Imports
-                    ModuleDecl::Import(_) => {
+                    // `import type { ... } from '...';` is skipped.
+                    ModuleDecl::Import(ImportDecl {
+                        type_only: false, ..
+                    }) => {
                         if self.runtime_module {
                             module_decl.visit_mut_with(self);
                         } else {
@@ -185,7 +259,8 @@ impl VisitMut for ModuleCollector {
                             if !self.runtime_module {
                                 module_body.push(self.get_default_export_stmt(ident).into());
                             }
-                        } else {
+                        } else if !self.runtime_module {
+                            // `export default interface ...`
                             module_body.push(module_decl.into());
                         }
                     }
@@ -216,39 +291,161 @@ impl VisitMut for ModuleCollector {
                             module_body.push(module_decl.into());
                         }
                     }
-                    _ => {
+                    // `import a = require('...');`
+                    // `export import a = require('...');`
+                    ModuleDecl::TsImportEquals(ts_import_equals_decl)
+                        if !ts_import_equals_decl.is_type_only =>
+                    {
+                        let is_module_ref = matches!(
+                            ts_import_equals_decl.module_ref,
+                            TsModuleRef::TsExternalModuleRef(_)
+                        );
+                        module_decl.visit_mut_with(self);
+                        if !self.runtime_module {
+                            module_body.push(module_decl.into());
+                        } else if !is_module_ref {
+                            // `import a = N.b;` is an alias of the local namespace.
+                            // `var a = N.b;`
+                            if let ModuleDecl::TsImportEquals(ts_import_equals_decl) = module_decl {
+                                let TsImportEqualsDecl {
+                                    span,
+                                    id,
+                                    module_ref,
+                                    ..
+                                } = *ts_import_equals_decl;
+                                if let TsModuleRef::TsEntityName(entity_name) = module_ref {
+                                    module_body.push(
+                                        decl_var_and_assign_stmt(
+                                            id,
+                                            span,
+                                            ts_entity_name_expr(entity_name),
+                                        )
+                                        .into(),
+                                    );
+                                }
+                            }
+                        }
+                    }
+                    // `export = Identifier`
+                    ModuleDecl::TsExportAssignment(ts_export_assignment) => {
+                        let (ident, stmt) = self
+                            .collect_export_assignment_and_convert_to_stmt(ts_export_assignment);
+                        module_body.push(stmt.into());
+                        if !self.runtime_module {
+                            module_body.push(
+                                ModuleDecl::TsExportAssignment(TsExportAssignment {
+                                    span: DUMMY_SP,
+                                    expr: Box::new(Expr::Ident(ident)),
+                                })
+                                .into(),
+                            );
+                        }
+                    }
+                    // Types only
+                    // `import type ...`, `export type ...`, `export as namespace ...`
+                    ModuleDecl::Import(_)
+                    | ModuleDecl::ExportNamed(_)
+                    | ModuleDecl::ExportAll(_)
+                    | ModuleDecl::TsImportEquals(_)
+                    | ModuleDecl::TsNamespaceExport(_) => {
                         if !self.runtime_module {
                             module_body.push(module_decl.into());
                         }
                     }
                 },
             };
+
+            // The first statement generated from the module item
+            // (the transformed import statements are generated with the span of the first import).
+            let generated_pos = module_body
+                .get(module_body_len)
+                .map(|module_item: &ModuleItem| module_item.span_lo())
+                .or_else(|| {
+                    self.imports
+                        .get(imports_len)
+                        .map(|import_module| import_module.span.lo)
+                })
+                .filter(|pos| !pos.is_dummy());
+            match generated_pos {
+                Some(generated_pos) => {
+                    self.move_leading_comments(module.span.lo, module_item_span.lo, generated_pos)
+                }
+                // Carried to the statement that registers the exports.
+                None if self.exports.len() > exports_len
+                    && module_item_span.lo != module.span.lo
+                    && self
+                        .comments
+                        .is_some_and(|comments| comments.has_leading(module_item_span.lo)) =>
+                {
+                    self.export_comment_spans.push(module_item_span);
+                }
+                // Left in place (eg. `// @ts-ignore` of `import type ...`).
+                None => {}
+            }
         }
         module.body = module_body;
+
+        if self.export_assignment.is_some() && !self.exports.is_empty() {
+            report_unsupported(
+                self.export_assignment_span,
+                "`export =` replaces the whole exports, other exports of the module are not registered",
+                self.strict,
+            );
+        }
     }
 
     fn visit_mut_import_decl(&mut self, import_decl: &mut ImportDecl) {
+        if import_decl.specifiers.is_empty() {
+            debug!("side effect import: {:#?}", import_decl.src.value);
+            self.imports.push(ImportModule {
+                span: import_decl.span,
+                ident: module_namespace_ident(&import_decl.src.value),
+                imported: None,
+                module_src: import_decl.src.value.to_string(),
+                module_type: ModuleType::SideEffect,
+                attributes: import_decl.with.clone(),
+            });
+            return;
+        }
+
         import_decl
             .specifiers
-            .to_owned()
-            .into_iter()
+            .iter()
+            .cloned()
             .for_each(|import_spec| match import_spec {
                 ImportSpecifier::Default(ImportDefaultSpecifier { span, local }) => {
                     debug!("default import: {:#?}", local.sym);
                     self.imports.push(ImportModule {
                         span,
                         ident: local,
+                        imported: None,
                         module_src: import_decl.src.value.to_string(),
                         module_type: ModuleType::Default,
+                        attributes: import_decl.with.clone(),
                     });
                 }
-                ImportSpecifier::Named(ImportNamedSpecifier { span, local, .. }) => {
+                ImportSpecifier::Named(ImportNamedSpecifier {
+                    span,
+                    local,
+                    imported,
+                    is_type_only: false,
+                }) => {
                     debug!("named import: {:#?}", local.sym);
+                    // `import { default as a } from '...';` is the same as `import a from '...';`
+                    let is_default = imported
+                        .as_ref()
+                        .is_some_and(|imported| &*module_export_name(imported) == "default");
                     self.imports.push(ImportModule {
                         span,
                         ident: local,
+                        imported,
                         module_src: import_decl.src.value.to_string(),
-                        module_type: ModuleType::Named,
+                        module_type: if is_default {
+                            ModuleType::Default
+                        } else {
+                            ModuleType::Named
+                        },
+                        attributes: import_decl.with.clone(),
                     });
                 }
                 ImportSpecifier::Namespace(ImportStarAsSpecifier { span, local }) => {
@@ -256,25 +453,28 @@ impl VisitMut for ModuleCollector {
                     self.imports.push(ImportModule {
                         span,
                         ident: local,
+                        imported: None,
                         module_src: import_decl.src.value.to_string(),
                         module_type: ModuleType::NamespaceOrAll,
+                        attributes: import_decl.with.clone(),
                     });
                 }
+                // `import { type a } from '...';`
+                ImportSpecifier::Named(_) => (),
             });
     }
 
     fn visit_mut_export_decl(&mut self, export_decl: &mut ExportDecl) {
         match &export_decl.decl {
+            // `export const a = 1, b = 2;`
+            // `export const { a, b: [c, d = 1, ...e], ...f } = obj;`
             Decl::Var(var_decl) => {
-                if let Some(var_ident) = var_decl
-                    .decls
-                    .get(0)
-                    .and_then(|var_declarator| var_declarator.name.as_ident())
-                {
-                    debug!("export decl var: {:#?}", var_ident.id.sym);
-                    self.exports
-                        .push(ExportModule::named(var_ident.id.clone(), None));
-                }
+                find_pat_ids::<_, Ident>(&var_decl.decls)
+                    .into_iter()
+                    .for_each(|var_ident| {
+                        debug!("export decl var: {:#?}", var_ident.sym);
+                        self.exports.push(ExportModule::named(var_ident, None));
+                    });
             }
             Decl::Fn(FnDecl { ident, .. }) => {
                 debug!("export decl fn: {:#?}", ident.sym);
@@ -284,70 +484,169 @@ impl VisitMut for ModuleCollector {
                 debug!("export decl class: {:#?}", ident.sym);
                 self.exports.push(ExportModule::named(ident.clone(), None));
             }
-            _ => (),
+            // `export enum A { ... }`
+            Decl::TsEnum(ts_enum_decl) if !ts_enum_decl.declare => {
+                debug!("export decl enum: {:#?}", ts_enum_decl.id.sym);
+                self.exports
+                    .push(ExportModule::named(ts_enum_decl.id.clone(), None));
+            }
+            // `export namespace A { ... }`
+            Decl::TsModule(ts_module_decl) if !ts_module_decl.declare => {
+                if let TsModuleName::Ident(ident) = &ts_module_decl.id {
+                    debug!("export decl namespace: {:#?}", ident.sym);
+                    self.exports.push(ExportModule::named(ident.clone(), None));
+                }
+            }
+            // `export using a = ...;` is not a valid syntax.
+            Decl::Using(using_decl) => {
+                report_unsupported(
+                    using_decl.span,
+                    "`using` declarations can't be exported, it is not registered",
+                    self.strict,
+                );
+            }
+            // Types and ambient declarations
+            Decl::TsInterface(_) | Decl::TsTypeAlias(_) | Decl::TsEnum(_) | Decl::TsModule(_) => (),
+        }
+    }
+
+    fn visit_mut_ts_import_equals_decl(&mut self, ts_import_equals_decl: &mut TsImportEqualsDecl) {
+        let TsImportEqualsDecl {
+            span,
+            is_export,
+            id,
+            module_ref,
+            ..
+        } = ts_import_equals_decl;
+
+        // Kept as it is in bundle time like the other import declarations.
+        if let (true, TsModuleRef::TsExternalModuleRef(TsExternalModuleRef { expr, .. })) =
+            (self.runtime_module, module_ref)
+        {
+            debug!("import equals: {:#?}", id.sym);
+            self.imports.push(ImportModule {
+                span: *span,
+                ident: id.clone(),
+                imported: None,
+                module_src: expr.value.to_string(),
+                module_type: ModuleType::NamespaceOrAll,
+                attributes: None,
+            });
+        }
+
+        if *is_export {
+            debug!("export import equals: {:#?}", id.sym);
+            self.exports.push(ExportModule::named(id.clone(), None));
         }
     }
 
     fn visit_mut_named_export(&mut self, named_export: &mut NamedExport) {
-        match named_export {
-            NamedExport { src: None, .. } => named_export.visit_mut_children_with(self),
-            NamedExport {
-                src: Some(module_src),
-                ..
-            } => {
-                if let Some(ExportSpecifier::Namespace(ExportNamespaceSpecifier {
-                    span,
-                    name: ModuleExportName::Ident(module_ident),
-                })) = named_export.specifiers.get(0)
-                {
-                    debug!("namespace export: {:#?}", module_ident.sym);
+        let module_src = match &named_export.src {
+            Some(module_src) => module_src.value.to_string(),
+            None => return named_export.visit_mut_children_with(self),
+        };
+
+        // Re-exported bindings are imported into private identifiers
+        // to avoid conflicts with local bindings (and `default`).
+        named_export
+            .specifiers
+            .iter()
+            .for_each(|export_spec| match export_spec {
+                // `export * as ns from '...';`
+                ExportSpecifier::Namespace(ExportNamespaceSpecifier { span, name }) => {
+                    debug!("namespace export: {:#?}", module_export_name(name));
                     let export_ident: Ident = private_ident!("__export_named");
                     self.imports.push(ImportModule {
                         span: *span,
                         ident: export_ident.clone(),
-                        module_src: module_src.value.to_string(),
+                        imported: None,
+                        module_src: module_src.clone(),
                         module_type: ModuleType::NamespaceOrAll,
+                        attributes: named_export.with.clone(),
                     });
                     self.exports.push(ExportModule::named(
                         export_ident,
-                        Some(module_ident.to_owned()),
+                        Some(module_export_name(name)),
                     ));
-                } else {
-                    named_export
-                        .specifiers
-                        .to_owned()
-                        .into_iter()
-                        .for_each(|import_spec| match import_spec {
-                            ExportSpecifier::Named(ExportNamedSpecifier { span, orig, .. }) => {
-                                if let ModuleExportName::Ident(orig_ident) = &orig {
-                                    debug!("named export: {:#?}", orig_ident.sym);
-                                    self.imports.push(ImportModule {
-                                        span,
-                                        ident: orig_ident.clone(),
-                                        module_src: module_src.value.to_string(),
-                                        module_type: ModuleType::Named,
-                                    });
-                                }
-                            }
-                            _ => (),
-                        });
-                    named_export.visit_mut_children_with(self);
                 }
-            }
-        }
+                // `export v from '...';`
+                ExportSpecifier::Default(ExportDefaultSpecifier { exported }) => {
+                    debug!("default re-export: {:#?}", exported.sym);
+                    let export_ident: Ident = private_ident!(exported.span, exported.sym.clone());
+                    self.imports.push(ImportModule {
+                        span: exported.span,
+                        ident: export_ident.clone(),
+                        imported: None,
+                        module_src: module_src.clone(),
+                        module_type: ModuleType::Default,
+                        attributes: named_export.with.clone(),
+                    });
+                    self.exports.push(ExportModule::named(
+                        export_ident,
+                        Some(exported.sym.clone()),
+                    ));
+                }
+                // `export { a, b as c, default as d, "e" as f } from '...';`
+                ExportSpecifier::Named(ExportNamedSpecifier {
+                    span,
+                    orig,
+                    exported,
+                    is_type_only: false,
+                }) => {
+                    let orig_name = module_export_name(orig);
+                    debug!("named re-export: {:#?}", orig_name);
+                    let is_default = &*orig_name == "default";
+                    let export_ident: Ident = match orig {
+                        ModuleExportName::Ident(orig_ident) if !is_default => {
+                            private_ident!(*span, orig_ident.sym.clone())
+                        }
+                        _ if is_default => private_ident!("__export_default"),
+                        _ => private_ident!("__export_named"),
+                    };
+                    self.imports.push(ImportModule {
+                        span: *span,
+                        ident: export_ident.clone(),
+                        imported: Some(orig.clone()),
+                        module_src: module_src.clone(),
+                        module_type: if is_default {
+                            ModuleType::Default
+                        } else {
+                            ModuleType::Named
+                        },
+                        attributes: named_export.with.clone(),
+                    });
+                    self.exports.push(ExportModule::named(
+                        export_ident,
+                        Some(exported.as_ref().map_or(orig_name, module_export_name)),
+                    ));
+                }
+                // `export { type a } from '...';`
+                ExportSpecifier::Named(_) => (),
+            });
     }
 
     fn visit_mut_export_named_specifier(&mut self, named_spec: &mut ExportNamedSpecifier) {
-        if let ModuleExportName::Ident(orig_ident) = &named_spec.orig {
-            debug!("named export: {:#?}", orig_ident.sym);
-            match &named_spec.exported {
-                Some(ModuleExportName::Ident(as_ident)) => self.exports.push(ExportModule::named(
+        if named_spec.is_type_only {
+            return;
+        }
+        match &named_spec.orig {
+            ModuleExportName::Ident(orig_ident) => {
+                debug!("named export: {:#?}", orig_ident.sym);
+                self.exports.push(ExportModule::named(
                     orig_ident.clone(),
-                    Some(as_ident.clone()),
-                )),
-                _ => self
-                    .exports
-                    .push(ExportModule::named(orig_ident.clone(), None)),
+                    named_spec.exported.as_ref().map(module_export_name),
+                ));
+            }
+            // `export { "a" as b };` is only valid with `from`.
+            ModuleExportName::Str(orig_str) => {
+                report_unsupported(
+                    orig_str.span,
+                    &format!(
+                        "string export name {:?} requires `from`, it is not registered",
+                        orig_str.value
+                    ),
+                    self.strict,
+                );
             }
         }
     }
@@ -357,12 +656,14 @@ impl VisitMut for ModuleCollector {
         self.imports.push(ImportModule {
             span: DUMMY_SP,
             ident: export_all_ident.clone(),
+            imported: None,
             module_src: export_all.src.value.to_string(),
             module_type: ModuleType::NamespaceOrAll,
+            attributes: export_all.with.clone(),
         });
         self.exports.push(ExportModule {
             ident: export_all_ident.clone(),
-            as_ident: None,
+            as_name: None,
             module_type: ModuleType::NamespaceOrAll,
         });
     }
@@ -0,0 +1,113 @@
+use crate::module_path::{is_package_specifier, normalize_module_path, relative_module_path};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[derive(Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ModuleIdStrategy {
+    // `./src/screens/Home`
+    #[default]
+    Path,
+    // `2a9c3f1e`
+    Hash,
+    // `12` (from `moduleIdMap`)
+    Numeric,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ModuleId {
+    Str(String),
+    Num(u32),
+}
+
+pub struct ModuleIdResolver {
+    strategy: ModuleIdStrategy,
+    root: Option<String>,
+    // Package specifiers as they are (eg. `react`, `chart.js`)
+    package_ids: HashMap<String, u32>,
+    // Normalized module paths (eg. `src/screens/Home`)
+    path_ids: HashMap<String, u32>,
+}
+
+impl ModuleIdResolver {
+    pub fn new(
+        strategy: ModuleIdStrategy,
+        root: Option<String>,
+        id_map: Option<HashMap<String, u32>>,
+    ) -> Self {
+        let mut package_ids = HashMap::new();
+        let mut path_ids = HashMap::new();
+        for (key, id) in id_map.unwrap_or_default() {
+            // Keys without `./`, `../` or `/` (eg. `react`, `src/screens/Home`)
+            // may be either a package specifier or a path relative to the root.
+            if is_package_specifier(&key) {
+                package_ids.insert(key.clone(), id);
+            }
+            path_ids.insert(normalize_module_path(&key), id);
+        }
+        ModuleIdResolver {
+            strategy,
+            root: root.map(|root| normalize_module_path(&root)),
+            package_ids,
+            path_ids,
+        }
+    }
+
+    pub fn strategy(&self) -> ModuleIdStrategy {
+        self.strategy
+    }
+
+    /// Get the registry id of the module.
+    ///
+    /// `module_path` is the normalized module path (or package specifier such as `react`)
+    /// and `is_package` is `true` when the module is not a file of the project.
+    pub fn get(&self, module_path: &str, is_package: bool) -> ModuleId {
+        if is_package {
+            return match self.strategy {
+                ModuleIdStrategy::Numeric => self
+                    .package_ids
+                    .get(module_path)
+                    .map_or(ModuleId::Str(module_path.to_string()), |id| {
+                        ModuleId::Num(*id)
+                    }),
+                _ => ModuleId::Str(module_path.to_string()),
+            };
+        }
+
+        let module_path = match &self.root {
+            Some(root) => relative_module_path(root, module_path),
+            None => module_path.to_string(),
+        };
+
+        match self.strategy {
+            ModuleIdStrategy::Path => ModuleId::Str(project_module_id(module_path)),
+            ModuleIdStrategy::Hash => ModuleId::Str(hash(&module_path)),
+            ModuleIdStrategy::Numeric => self
+                .path_ids
+                .get(&module_path)
+                .map_or(ModuleId::Str(project_module_id(module_path)), |id| {
+                    ModuleId::Num(*id)
+                }),
+        }
+    }
+}
+
+// Module paths of the project are prefixed with `./` (unless they are `../` or absolute paths)
+// not to collide with the package specifiers (eg. `/app/react.ts` and `react`).
+fn project_module_id(module_path: String) -> String {
+    if module_path.is_empty() {
+        String::from(".")
+    } else if is_package_specifier(&module_path) {
+        format!("./{}", module_path)
+    } else {
+        module_path
+    }
+}
+
+// 32-bit FNV-1a
+fn hash(value: &str) -> String {
+    let hash = value.bytes().fold(0x811c9dc5_u32, |hash, byte| {
+        (hash ^ byte as u32).wrapping_mul(0x01000193)
+    });
+    format!("{:08x}", hash)
+}
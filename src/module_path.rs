@@ -0,0 +1,114 @@
+// Extensions that can be omitted from import specifiers.
+const SOURCE_EXTENSIONS: [&str; 8] = ["tsx", "ts", "mts", "cts", "jsx", "js", "mjs", "cjs"];
+
+// Platform specific extensions resolved by the bundler.
+// eg. `Button.ios.tsx` is imported as `./Button`
+const PLATFORM_EXTENSIONS: [&str; 4] = ["ios", "android", "native", "web"];
+
+fn is_relative(module_src: &str) -> bool {
+    module_src == "."
+        || module_src == ".."
+        || module_src.starts_with("./")
+        || module_src.starts_with("../")
+}
+
+/// Returns `true` if the specifier refers to a package (eg. `react`, `@app/core`)
+/// rather than a file of the project.
+pub fn is_package_specifier(module_src: &str) -> bool {
+    !is_relative(module_src) && !module_src.starts_with('/')
+}
+
+fn strip_extension(file_name: &str) -> &str {
+    let mut file_name = file_name;
+    if let Some((stem, ext)) = file_name.rsplit_once('.') {
+        if !stem.is_empty() && SOURCE_EXTENSIONS.contains(&ext) {
+            file_name = stem;
+        }
+    }
+    if let Some((stem, ext)) = file_name.rsplit_once('.') {
+        if !stem.is_empty() && PLATFORM_EXTENSIONS.contains(&ext) {
+            file_name = stem;
+        }
+    }
+    file_name
+}
+
+/// Normalize the module path to the key that is used in the module registry.
+///
+/// - `\` separators are replaced with `/`
+/// - `.` and `..` segments are collapsed
+/// - source and platform extensions are stripped (`Button.ios.tsx` -> `Button`)
+/// - trailing `index` is stripped (`components/index.ts` -> `components`)
+pub fn normalize_module_path(path: &str) -> String {
+    let path = path.replace('\\', "/");
+    let is_absolute = path.starts_with('/');
+    let mut segments: Vec<&str> = Vec::new();
+
+    for segment in path.split('/') {
+        match segment {
+            "" | "." => (),
+            ".." => match segments.last() {
+                Some(&last) if last != ".." => {
+                    segments.pop();
+                }
+                _ => {
+                    if !is_absolute {
+                        segments.push(segment);
+                    }
+                }
+            },
+            _ => segments.push(segment),
+        }
+    }
+
+    if let Some(file_name) = segments.pop() {
+        let stem = strip_extension(file_name);
+        if stem != "index" || segments.is_empty() {
+            segments.push(stem);
+        }
+    }
+
+    let normalized = segments.join("/");
+    if is_absolute {
+        format!("/{}", normalized)
+    } else {
+        normalized
+    }
+}
+
+/// Resolve the import specifier to the module path of the importing file.
+///
+/// Relative specifiers (`./foo`, `../foo`) are joined to the directory of `filename`
+/// and absolute specifiers are normalized. Package specifiers (`react`) are kept as they are.
+pub fn resolve_module_src(filename: &str, module_src: &str) -> String {
+    if is_relative(module_src) {
+        let filename = filename.replace('\\', "/");
+        let dirname = filename.rsplit_once('/').map_or("", |(dirname, _)| dirname);
+        if dirname.is_empty() && !filename.starts_with('/') {
+            normalize_module_path(module_src)
+        } else {
+            normalize_module_path(&format!("{}/{}", dirname, module_src))
+        }
+    } else if module_src.starts_with('/') {
+        normalize_module_path(module_src)
+    } else {
+        module_src.to_string()
+    }
+}
+
+/// Get the path of `module_path` relative to `root` (both are normalized paths).
+///
+/// eg. `/app` and `/app/src/screens/Home` -> `src/screens/Home`
+pub fn relative_module_path(root: &str, module_path: &str) -> String {
+    let root_segments: Vec<&str> = root.split('/').filter(|s| !s.is_empty()).collect();
+    let path_segments: Vec<&str> = module_path.split('/').filter(|s| !s.is_empty()).collect();
+    let common_len = root_segments
+        .iter()
+        .zip(path_segments.iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let mut segments = vec![".."; root_segments.len() - common_len];
+    segments.extend_from_slice(&path_segments[common_len..]);
+    segments.join("/")
+}
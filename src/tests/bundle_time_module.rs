@@ -1,14 +1,21 @@
-use super::ReactNativeEsbuildModule;
-use swc_core::ecma::{
-    transforms::testing::test,
-    visit::{as_folder, Folder},
+use super::{ReactNativeEsbuildModule, ReactNativeEsbuildModuleOptions};
+use swc_core::{
+    common::Mark,
+    ecma::{
+        transforms::testing::test,
+        visit::{as_folder, Folder},
+    },
 };
 
 fn plugin() -> Folder<ReactNativeEsbuildModule> {
-    as_folder(ReactNativeEsbuildModule {
-        module_name: String::from("test.js"),
-        runtime_module: false, // bundle time
-    })
+    as_folder(ReactNativeEsbuildModule::new(
+        String::from("test.js"),
+        Mark::new(),
+        ReactNativeEsbuildModuleOptions {
+            runtime_module: Some(false), // bundle time
+            ..Default::default()
+        },
+    ))
 }
 
 test!(
@@ -22,7 +29,7 @@ test!(
     // Output codes after transformed with plugin
     r#"
     export const named = new Instance();
-    global.__modules.export("test.js", { "named": named });
+    global.__modules.export("./test", { "named": named });
     "#
 );
 
@@ -41,7 +48,7 @@ test!(
     export function namedFunction() {
         console.log('body');
     }
-    global.__modules.export("test.js", { "namedFunction": namedFunction });
+    global.__modules.export("./test", { "namedFunction": namedFunction });
     "#
 );
 
@@ -60,7 +67,7 @@ test!(
     const plain = 0;
     const beforeRename = 1;
     export { plain, beforeRename as afterRename };
-    global.__modules.export("test.js", {
+    global.__modules.export("./test", {
         "plain": plain,
         "afterRename": beforeRename
     });
@@ -79,7 +86,7 @@ test!(
     r#"
     var __export_named = global.__modules.import("module");
     export * as rename from 'module';
-    global.__modules.export("test.js", { "rename": __export_named });
+    global.__modules.export("./test", { "rename": __export_named });
     "#
 );
 
@@ -95,7 +102,7 @@ test!(
     r#"
     var __export_default = 0;
     export default __export_default;
-    global.__modules.export("test.js", {
+    global.__modules.export("./test", {
         "default": __export_default
     });
     "#
@@ -113,7 +120,7 @@ test!(
     r#"
     class ClassDecl {}
     export default ClassDecl;
-    global.__modules.export("test.js", {
+    global.__modules.export("./test", {
         "default": ClassDecl
     });
     "#
@@ -131,7 +138,7 @@ test!(
     r#"
     var __export_default = class {}
     export default __export_default;
-    global.__modules.export("test.js", {
+    global.__modules.export("./test", {
         "default": __export_default
     });
     "#
@@ -147,9 +154,37 @@ test!(
     "#,
     // Output codes after transformed with plugin
     r#"
+    var __exportStar = function (exports, sources) {
+        var hasOwn = Object.prototype.hasOwnProperty;
+        var names = {};
+        sources.forEach(function (source) {
+            source && Object.keys(source).forEach(function (name) {
+                if (name === 'default' || name === '__esModule' || hasOwn.call(exports, name)) {
+                    return;
+                }
+                if (hasOwn.call(names, name)) {
+                    if (names[name] && names[name][name] !== source[name]) {
+                        names[name] = null;
+                    }
+                    return;
+                }
+                names[name] = source;
+            });
+        });
+        Object.keys(names).forEach(function (name) {
+            var source = names[name];
+            source && Object.defineProperty(exports, name, {
+                enumerable: true,
+                get: function () {
+                    return source[name];
+                },
+            });
+        });
+        return exports;
+    };
     var __export_all = global.__modules.import("module");
     export * from 'module';
-    global.__modules.export("test.js", { ...__export_all });
+    global.__modules.export("./test", __exportStar({}, [__export_all]));
     "#
 );
 
@@ -163,11 +198,12 @@ test!(
     "#,
     // Output codes after transformed with plugin
     r#"
-    var a = global.__modules.import("module").a;
-    var b = global.__modules.import("module").b;
-    var c = global.__modules.import("module").c;
+    var _module = global.__modules.import("module");
+    var a = _module.a;
+    var b = _module.b;
+    var c = _module.c;
     export { a, b, c } from 'module';
-    global.__modules.export("test.js", {
+    global.__modules.export("./test", {
         "a": a,
         "b": b,
         "c": c
@@ -212,7 +248,7 @@ test!(
     };
     export default __export_default;
     export { app, useCustomHook };
-    global.__modules.export("test.js", {
+    global.__modules.export("./test", {
         "MyComponent": MyComponent,
         "default": __export_default,
         "app": app,
@@ -220,3 +256,45 @@ test!(
     });
     "#
 );
+
+test!(
+    Default::default(),
+    |_| plugin(),
+    bundle_time_dynamic_import,
+    // Input codes
+    r#"
+    const Settings = React.lazy(() => import('./screens/Settings'));
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    const Settings = React.lazy(() => import('./screens/Settings'));
+    "#
+);
+
+test!(
+    Default::default(),
+    |_| plugin(),
+    bundle_time_export_destructuring_var_decl,
+    // Input codes
+    r#"
+    export const a = 1, b = 2;
+    export const { width, height: h, ...rest } = Dimensions.get('window');
+    export let [first, [second = 0], ...others] = list;
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    export const a = 1, b = 2;
+    export const { width, height: h, ...rest } = Dimensions.get('window');
+    export let [first, [second = 0], ...others] = list;
+    global.__modules.export("./test", {
+        "a": a,
+        "b": b,
+        "width": width,
+        "h": h,
+        "rest": rest,
+        "first": first,
+        "second": second,
+        "others": others
+    });
+    "#
+);
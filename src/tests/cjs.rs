@@ -0,0 +1,155 @@
+use super::{ReactNativeEsbuildModule, ReactNativeEsbuildModuleOptions};
+use swc_core::{
+    common::{chain, Mark},
+    ecma::{
+        transforms::{base::resolver, testing::test},
+        visit::{as_folder, Fold},
+    },
+};
+
+fn plugin(runtime_module: bool, factory: bool) -> impl Fold {
+    let unresolved_mark = Mark::new();
+    chain!(
+        resolver(unresolved_mark, Mark::new(), false),
+        as_folder(ReactNativeEsbuildModule::new(
+            String::from("/app/node_modules/lib/index.js"),
+            unresolved_mark,
+            ReactNativeEsbuildModuleOptions {
+                runtime_module: Some(runtime_module),
+                factory: Some(factory),
+                commonjs: Some(true),
+                ..Default::default()
+            },
+        ))
+    )
+}
+
+test!(
+    Default::default(),
+    |_| plugin(true, false),
+    cjs_module,
+    // Input codes
+    r#"
+    const React = require('react');
+    const { helper } = require('./helper');
+    exports.name = 'lib';
+    module.exports.Component = function Component() {
+        return require('./lazy').default;
+    };
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var __module = { exports: {} };
+    var __exports = __module.exports;
+    const React = global.__modules.import("react");
+    const { helper } = global.__modules.import("/app/node_modules/lib/helper");
+    __exports.name = 'lib';
+    __module.exports.Component = function Component() {
+        return global.__modules.import("/app/node_modules/lib/lazy").default;
+    };
+    global.__modules.export("/app/node_modules/lib", __module.exports);
+    "#
+);
+
+test!(
+    Default::default(),
+    |_| plugin(true, false),
+    cjs_shadowed_require,
+    // Input codes
+    r#"
+    function load(require, module) {
+        return require('react') || module.exports;
+    }
+    module.exports = load;
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var __module = { exports: {} };
+    var __exports = __module.exports;
+    function load(require, module) {
+        return require('react') || module.exports;
+    }
+    __module.exports = load;
+    global.__modules.export("/app/node_modules/lib", __module.exports);
+    "#
+);
+
+test!(
+    Default::default(),
+    |_| plugin(true, true),
+    cjs_factory,
+    // Input codes
+    r#"
+    module.exports = require('./impl');
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    global.__modules.define("/app/node_modules/lib", ["/app/node_modules/lib/impl"], function () {
+        var __module = { exports: {} };
+        var __exports = __module.exports;
+        __module.exports = global.__modules.import("/app/node_modules/lib/impl");
+        global.__modules.export("/app/node_modules/lib", __module.exports);
+    });
+    "#
+);
+
+test!(
+    Default::default(),
+    |_| plugin(false, false),
+    cjs_bundle_time,
+    // Input codes
+    r#"
+    const React = require('react');
+    module.exports = React;
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    const React = require('react');
+    module.exports = React;
+    global.__modules.export("/app/node_modules/lib", module.exports);
+    "#
+);
+
+test!(
+    Default::default(),
+    |_| plugin(true, false),
+    cjs_require_in_esm,
+    // Input codes
+    r#"
+    import React from 'react';
+    const image = require('./image.png');
+    export default image;
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var React = global.__modules.import("react").default;
+    const image = global.__modules.import("/app/node_modules/lib/image.png");
+    var __export_default = image;
+    global.__modules.export("/app/node_modules/lib", {
+        "default": __export_default
+    });
+    "#
+);
+
+test!(
+    Default::default(),
+    |_| plugin(true, true),
+    cjs_use_strict,
+    // Input codes
+    r#"
+    'use strict';
+    const React = require('react');
+    module.exports = React;
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    global.__modules.define("/app/node_modules/lib", ["react"], function () {
+        'use strict';
+        var __module = { exports: {} };
+        var __exports = __module.exports;
+        const React = global.__modules.import("react");
+        __module.exports = React;
+        global.__modules.export("/app/node_modules/lib", __module.exports);
+    });
+    "#
+);
@@ -0,0 +1,99 @@
+use super::{harness, ReactNativeEsbuildModuleOptions};
+use swc_core::common::Spanned;
+
+fn transform(code: &str) -> (Vec<String>, Vec<Vec<String>>) {
+    transform_with_options(
+        code,
+        ReactNativeEsbuildModuleOptions {
+            runtime_module: Some(true),
+            ..Default::default()
+        },
+    )
+}
+
+// Returns the leading comments of the module and each generated statement.
+fn transform_with_options(
+    code: &str,
+    options: ReactNativeEsbuildModuleOptions,
+) -> (Vec<String>, Vec<Vec<String>>) {
+    let transformed = harness::transform("test.js", code, Default::default(), options);
+    (
+        transformed.leading_comments(transformed.module.span.lo),
+        transformed
+            .module
+            .body
+            .iter()
+            .map(|module_item| {
+                if module_item.span().is_dummy() {
+                    return Vec::new();
+                }
+                transformed.leading_comments(module_item.span_lo())
+            })
+            .collect(),
+    )
+}
+
+#[test]
+fn import_and_export_comments() {
+    assert_eq!(
+        transform(
+            "const a = 1;\n\
+             // @ts-ignore\n\
+             import React, { useState } from 'react';\n\
+             /** Exported value */\n\
+             export const b = a;\n\
+             // Re-exported\n\
+             export { a };\n\
+             export default b;"
+        ),
+        (
+            vec![],
+            vec![
+                // `var _react = ...` and `var React = _react.default` share the span of `React`.
+                vec![String::from("@ts-ignore")],
+                vec![String::from("@ts-ignore")],
+                vec![],
+                vec![],
+                vec![String::from("* Exported value")],
+                vec![],
+                // `export { a };` has no statement, its comments are carried to the exports.
+                vec![String::from("Re-exported")],
+            ]
+        )
+    );
+}
+
+#[test]
+fn module_header_comments() {
+    let (module_comments, _) = transform(
+        "/* @license MIT */\n\
+         // @flow\n\
+         import { a } from './a';\n\
+         export * from './b';",
+    );
+    assert_eq!(module_comments, vec!["@license MIT", "@flow"]);
+}
+
+#[test]
+fn live_export_comments() {
+    let (_, stmt_comments) = transform_with_options(
+        "import { a } from './a';\n\
+         // Re-exported\n\
+         export { a };\n\
+         // Unrelated\n\
+         console.log(a);",
+        ReactNativeEsbuildModuleOptions {
+            runtime_module: Some(true),
+            live_exports: Some(true),
+            ..Default::default()
+        },
+    );
+    assert_eq!(
+        stmt_comments,
+        vec![
+            vec![String::from("Re-exported")],
+            vec![],
+            vec![String::from("Unrelated")],
+        ]
+    );
+}
@@ -1,14 +1,22 @@
-use super::ReactNativeEsbuildModule;
-use swc_core::ecma::{
-    transforms::testing::test,
-    visit::{as_folder, Folder},
+use super::{ReactNativeEsbuildModule, ReactNativeEsbuildModuleOptions};
+use swc_core::{
+    common::Mark,
+    ecma::{
+        parser::{EsConfig, Syntax},
+        transforms::testing::test,
+        visit::{as_folder, Folder},
+    },
 };
 
 fn plugin() -> Folder<ReactNativeEsbuildModule> {
-    as_folder(ReactNativeEsbuildModule {
-        module_name: String::from("test.js"),
-        runtime_module: true,
-    })
+    as_folder(ReactNativeEsbuildModule::new(
+        String::from("test.js"),
+        Mark::new(),
+        ReactNativeEsbuildModuleOptions {
+            runtime_module: Some(true),
+            ..Default::default()
+        },
+    ))
 }
 
 test!(
@@ -22,7 +30,7 @@ test!(
     // Output codes after transformed with plugin
     r#"
     const named = new Instance();
-    global.__modules.export("test.js", { "named": named });
+    global.__modules.export("./test", { "named": named });
     "#
 );
 
@@ -41,7 +49,7 @@ test!(
     function namedFunction() {
         console.log('body');
     }
-    global.__modules.export("test.js", { "namedFunction": namedFunction });
+    global.__modules.export("./test", { "namedFunction": namedFunction });
     "#
 );
 
@@ -59,7 +67,7 @@ test!(
     r#"
     const plain = 0;
     const beforeRename = 1;
-    global.__modules.export("test.js", {
+    global.__modules.export("./test", {
         "plain": plain,
         "afterRename": beforeRename
     });
@@ -77,7 +85,7 @@ test!(
     // Output codes after transformed with plugin
     r#"
     var __export_named = global.__modules.import("module");
-    global.__modules.export("test.js", { "rename": __export_named });
+    global.__modules.export("./test", { "rename": __export_named });
     "#
 );
 
@@ -92,7 +100,7 @@ test!(
     // Output codes after transformed with plugin
     r#"
     var __export_default = 0;
-    global.__modules.export("test.js", {
+    global.__modules.export("./test", {
         "default": __export_default
     });
     "#
@@ -109,7 +117,7 @@ test!(
     // Output codes after transformed with plugin
     r#"
     class ClassDecl {}
-    global.__modules.export("test.js", {
+    global.__modules.export("./test", {
         "default": ClassDecl
     });
     "#
@@ -126,7 +134,7 @@ test!(
     // Output codes after transformed with plugin
     r#"
     var __export_default = class {}
-    global.__modules.export("test.js", {
+    global.__modules.export("./test", {
         "default": __export_default
     });
     "#
@@ -142,8 +150,36 @@ test!(
     "#,
     // Output codes after transformed with plugin
     r#"
+    var __exportStar = function (exports, sources) {
+        var hasOwn = Object.prototype.hasOwnProperty;
+        var names = {};
+        sources.forEach(function (source) {
+            source && Object.keys(source).forEach(function (name) {
+                if (name === 'default' || name === '__esModule' || hasOwn.call(exports, name)) {
+                    return;
+                }
+                if (hasOwn.call(names, name)) {
+                    if (names[name] && names[name][name] !== source[name]) {
+                        names[name] = null;
+                    }
+                    return;
+                }
+                names[name] = source;
+            });
+        });
+        Object.keys(names).forEach(function (name) {
+            var source = names[name];
+            source && Object.defineProperty(exports, name, {
+                enumerable: true,
+                get: function () {
+                    return source[name];
+                },
+            });
+        });
+        return exports;
+    };
     var __export_all = global.__modules.import("module");
-    global.__modules.export("test.js", { ...__export_all });
+    global.__modules.export("./test", __exportStar({}, [__export_all]));
     "#
 );
 
@@ -157,10 +193,11 @@ test!(
     "#,
     // Output codes after transformed with plugin
     r#"
-    var a = global.__modules.import("module").a;
-    var b = global.__modules.import("module").b;
-    var c = global.__modules.import("module").c;
-    global.__modules.export("test.js", {
+    var _module = global.__modules.import("module");
+    var a = _module.a;
+    var b = _module.b;
+    var c = _module.c;
+    global.__modules.export("./test", {
         "a": a,
         "b": b,
         "c": c
@@ -179,7 +216,7 @@ test!(
     // Output codes after transformed with plugin
     r#"
     var __dummy = global.__modules.import("dummy").default;
-    global.__modules.export("test.js", null);
+    global.__modules.export("./test", null);
     "#
 );
 
@@ -208,13 +245,15 @@ test!(
     "#,
     // Output codes after transformed with plugin
     r#"
-    var React = global.__modules.import("react").default;
-    var useState = global.__modules.import("react").useState;
-    var useEffect = global.__modules.import("react").useEffect;
-    var Container = global.__modules.import("@app/components").Container;
-    var Section = global.__modules.import("@app/components").Section;
-    var Button = global.__modules.import("@app/components").Button;
-    var Text = global.__modules.import("@app/components").Text;
+    var _react = global.__modules.import("react");
+    var React = _react.default;
+    var useState = _react.useState;
+    var useEffect = _react.useEffect;
+    var _components = global.__modules.import("@app/components");
+    var Container = _components.Container;
+    var Section = _components.Section;
+    var Button = _components.Button;
+    var Text = _components.Text;
     var useCustomHook = global.__modules.import("@app/hooks").useCustomHook;
     var app = global.__modules.import("@app/core");
     function MyComponent() {
@@ -223,7 +262,7 @@ test!(
     var __export_default = class {
         init() {}
     };
-    global.__modules.export("test.js", {
+    global.__modules.export("./test", {
         "MyComponent": MyComponent,
         "default": __export_default,
         "app": app,
@@ -231,3 +270,115 @@ test!(
     });
     "#
 );
+
+test!(
+    Default::default(),
+    |_| plugin(),
+    export_all_with_explicit_exports,
+    // Input codes
+    r#"
+    export * from 'module-a';
+    export * from 'module-b';
+    export const value = 1;
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var __exportStar = function (exports, sources) {
+        var hasOwn = Object.prototype.hasOwnProperty;
+        var names = {};
+        sources.forEach(function (source) {
+            source && Object.keys(source).forEach(function (name) {
+                if (name === 'default' || name === '__esModule' || hasOwn.call(exports, name)) {
+                    return;
+                }
+                if (hasOwn.call(names, name)) {
+                    if (names[name] && names[name][name] !== source[name]) {
+                        names[name] = null;
+                    }
+                    return;
+                }
+                names[name] = source;
+            });
+        });
+        Object.keys(names).forEach(function (name) {
+            var source = names[name];
+            source && Object.defineProperty(exports, name, {
+                enumerable: true,
+                get: function () {
+                    return source[name];
+                },
+            });
+        });
+        return exports;
+    };
+    var __export_all = global.__modules.import("module-a");
+    var __export_all1 = global.__modules.import("module-b");
+    const value = 1;
+    global.__modules.export("./test", __exportStar({
+        "value": value
+    }, [__export_all, __export_all1]));
+    "#
+);
+
+test!(
+    Syntax::Es(EsConfig {
+        export_default_from: true,
+        ..Default::default()
+    }),
+    |_| plugin(),
+    export_renamed_from,
+    // Input codes
+    r#"
+    const a = 'local';
+    export { a as b, default, default as Button, "x-y" as xy } from 'module';
+    export v from 'module-v';
+    export { a, a as "a-a" };
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var _module = global.__modules.import("module");
+    var a = _module.a;
+    var __export_default = _module.default;
+    var __export_default1 = _module.default;
+    var __export_named = _module["x-y"];
+    var v = global.__modules.import("module-v").default;
+    const a1 = 'local';
+    global.__modules.export("./test", {
+        "b": a,
+        "default": __export_default,
+        "Button": __export_default1,
+        "xy": __export_named,
+        "v": v,
+        "a": a1,
+        "a-a": a1
+    });
+    "#
+);
+
+test!(
+    Default::default(),
+    |_| plugin(),
+    export_destructuring_var_decl,
+    // Input codes
+    r#"
+    export const a = 1, b = 2;
+    export const { width, height: h, ...rest } = Dimensions.get('window');
+    export let [first, [second = 0], ...others] = list;
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    const a = 1, b = 2;
+    const { width, height: h, ...rest } = Dimensions.get('window');
+    let [first, [second = 0], ...others] = list;
+    global.__modules.export("./test", {
+        "a": a,
+        "b": b,
+        "width": width,
+        "h": h,
+        "rest": rest,
+        "first": first,
+        "second": second,
+        "others": others
+    });
+    "#
+);
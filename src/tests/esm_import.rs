@@ -1,14 +1,21 @@
-use super::ReactNativeEsbuildModule;
-use swc_core::ecma::{
-    transforms::testing::test,
-    visit::{as_folder, Folder},
+use super::{ReactNativeEsbuildModule, ReactNativeEsbuildModuleOptions};
+use swc_core::{
+    common::Mark,
+    ecma::{
+        transforms::testing::test,
+        visit::{as_folder, Folder},
+    },
 };
 
 fn plugin() -> Folder<ReactNativeEsbuildModule> {
-    as_folder(ReactNativeEsbuildModule {
-        module_name: String::from("test.js"),
-        runtime_module: true,
-    })
+    as_folder(ReactNativeEsbuildModule::new(
+        String::from("test.js"),
+        Mark::new(),
+        ReactNativeEsbuildModuleOptions {
+            runtime_module: Some(true),
+            ..Default::default()
+        },
+    ))
 }
 
 test!(
@@ -22,7 +29,7 @@ test!(
     // Output codes after transformed with plugin
     r#"
     var React = global.__modules.import("react").default;
-    global.__modules.export("test.js", null);
+    global.__modules.export("./test", null);
     "#
 );
 
@@ -36,9 +43,10 @@ test!(
     "#,
     // Output codes after transformed with plugin
     r#"
-    var useState = global.__modules.import("react").useState;
-    var useContext = global.__modules.import("react").useContext;
-    global.__modules.export("test.js", null);
+    var _react = global.__modules.import("react");
+    var useState = _react.useState;
+    var useContext = _react.useContext;
+    global.__modules.export("./test", null);
     "#
 );
 
@@ -52,10 +60,11 @@ test!(
     "#,
     // Output codes after transformed with plugin
     r#"
-    var React = global.__modules.import("react").default;
-    var useState = global.__modules.import("react").useState;
-    var useContext = global.__modules.import("react").useContext;
-    global.__modules.export("test.js", null);
+    var _react = global.__modules.import("react");
+    var React = _react.default;
+    var useState = _react.useState;
+    var useContext = _react.useContext;
+    global.__modules.export("./test", null);
     "#
 );
 
@@ -70,7 +79,7 @@ test!(
     // Output codes after transformed with plugin
     r#"
     var ReactAll = global.__modules.import("react");
-    global.__modules.export("test.js", null);
+    global.__modules.export("./test", null);
     "#
 );
 
@@ -86,11 +95,85 @@ test!(
     "#,
     // Output codes after transformed with plugin
     r#"
-    var React = global.__modules.import("react").default;
-    var useState = global.__modules.import("react").useState;
-    var useContext = global.__modules.import("react").useContext;
+    var _react = global.__modules.import("react");
+    var React = _react.default;
+    var useState = _react.useState;
+    var useContext = _react.useContext;
     function testFn() {}
     class TestClass {}
-    global.__modules.export("test.js", null);
+    global.__modules.export("./test", null);
+    "#
+);
+
+test!(
+    Default::default(),
+    |_| plugin(),
+    renamed_import,
+    // Input codes
+    r#"
+    import { useState as useS, "use-effect" as useE, default as R } from 'react';
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var _react = global.__modules.import("react");
+    var useS = _react.useState;
+    var useE = _react["use-effect"];
+    var R = _react.default;
+    global.__modules.export("./test", null);
+    "#
+);
+
+test!(
+    Default::default(),
+    |_| plugin(),
+    side_effect_import,
+    // Input codes
+    r#"
+    import './polyfill';
+    import React from 'react';
+    import 'react-native-gesture-handler';
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    global.__modules.import("./polyfill");
+    var React = global.__modules.import("react").default;
+    global.__modules.import("react-native-gesture-handler");
+    global.__modules.export("./test", null);
+    "#
+);
+
+test!(
+    Default::default(),
+    |_| plugin(),
+    dynamic_import,
+    // Input codes
+    r#"
+    const Settings = React.lazy(() => import('./screens/Settings'));
+    import(moduleName).then(console.log);
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    const Settings = React.lazy(() => global.__modules.importAsync("./screens/Settings"));
+    import(moduleName).then(console.log);
+    global.__modules.export("./test", null);
+    "#
+);
+
+test!(
+    Default::default(),
+    |_| plugin(),
+    cached_module_namespace_import,
+    // Input codes
+    r#"
+    import * as app from '@app/core';
+    import { config } from '@app/core';
+    import { Button } from '@app/components';
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var app = global.__modules.import("@app/core");
+    var config = app.config;
+    var Button = global.__modules.import("@app/components").Button;
+    global.__modules.export("./test", null);
     "#
 );
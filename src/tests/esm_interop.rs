@@ -0,0 +1,124 @@
+use super::{ReactNativeEsbuildModule, ReactNativeEsbuildModuleOptions};
+use swc_core::{
+    common::{chain, Mark},
+    ecma::{
+        parser::{EsConfig, Syntax},
+        transforms::{base::resolver, testing::test},
+        visit::{as_folder, Fold},
+    },
+};
+
+fn plugin(live_bindings: bool) -> impl Fold {
+    let unresolved_mark = Mark::new();
+    chain!(
+        resolver(unresolved_mark, Mark::new(), false),
+        as_folder(ReactNativeEsbuildModule::new(
+            String::from("test.js"),
+            unresolved_mark,
+            ReactNativeEsbuildModuleOptions {
+                runtime_module: Some(true),
+                live_bindings: Some(live_bindings),
+                es_module_interop: Some(true),
+                ..Default::default()
+            },
+        ))
+    )
+}
+
+test!(
+    Default::default(),
+    |_| plugin(false),
+    interop_default_import,
+    // Input codes
+    r#"
+    import React, { useState } from 'react';
+    import * as lib from 'cjs-lib';
+    export const value = 1;
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var __interopDefault = function (m) {
+        return m && m.__esModule ? m : { default: m };
+    };
+    var _react = global.__modules.import("react");
+    var _react_default = __interopDefault(_react);
+    var React = _react_default.default;
+    var useState = _react.useState;
+    var lib = global.__modules.import("cjs-lib");
+    const value = 1;
+    global.__modules.export("./test", {
+        "__esModule": true,
+        "value": value
+    });
+    "#
+);
+
+test!(
+    Default::default(),
+    |_| plugin(false),
+    interop_default_named_import,
+    // Input codes
+    r#"
+    import { default as lib, helper } from 'cjs-lib';
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var __interopDefault = function (m) {
+        return m && m.__esModule ? m : { default: m };
+    };
+    var _cjs_lib = global.__modules.import("cjs-lib");
+    var _cjs_lib_default = __interopDefault(_cjs_lib);
+    var lib = _cjs_lib_default.default;
+    var helper = _cjs_lib.helper;
+    global.__modules.export("./test", null);
+    "#
+);
+
+test!(
+    Syntax::Es(EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |_| plugin(true),
+    interop_default_import_with_live_binding,
+    // Input codes
+    r#"
+    import React, { useState } from 'react';
+    <React.Fragment>{useState}</React.Fragment>;
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var __interopDefault = function (m) {
+        return m && m.__esModule ? m : { default: m };
+    };
+    var _react = global.__modules.import("react");
+    var _react_default = __interopDefault(_react);
+    <_react_default.default.Fragment>{_react.useState}</_react_default.default.Fragment>;
+    global.__modules.export("./test", null);
+    "#
+);
+
+test!(
+    Default::default(),
+    |_| plugin(false),
+    interop_use_strict,
+    // Input codes
+    r#"
+    'use strict';
+    import React from 'react';
+    export default React;
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    'use strict';
+    var __interopDefault = function (m) {
+        return m && m.__esModule ? m : { default: m };
+    };
+    var React = __interopDefault(global.__modules.import("react")).default;
+    var __export_default = React;
+    global.__modules.export("./test", {
+        "__esModule": true,
+        "default": __export_default
+    });
+    "#
+);
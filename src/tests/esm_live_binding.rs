@@ -0,0 +1,200 @@
+use super::{ReactNativeEsbuildModule, ReactNativeEsbuildModuleOptions};
+use swc_core::{
+    common::{chain, Mark},
+    ecma::{
+        parser::{EsConfig, Syntax},
+        transforms::{base::resolver, testing::test},
+        visit::{as_folder, Fold},
+    },
+};
+
+fn plugin() -> impl Fold {
+    let unresolved_mark = Mark::new();
+    chain!(
+        resolver(unresolved_mark, Mark::new(), false),
+        as_folder(ReactNativeEsbuildModule::new(
+            String::from("test.js"),
+            unresolved_mark,
+            ReactNativeEsbuildModuleOptions {
+                runtime_module: Some(true),
+                live_bindings: Some(true),
+                ..Default::default()
+            },
+        ))
+    )
+}
+
+test!(
+    Default::default(),
+    |_| plugin(),
+    live_binding_import,
+    // Input codes
+    r#"
+    import React, { useState, useEffect as useE } from 'react';
+    import * as app from '@app/core';
+    import { "x-y" as xy } from './utils';
+    useE(() => {
+        console.log(React.version, app.name, xy);
+    }, []);
+    const [state, setState] = useState(0);
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var _react = global.__modules.import("react");
+    var app = global.__modules.import("@app/core");
+    var _utils = global.__modules.import("./utils");
+    (0, _react.useEffect)(() => {
+        console.log(_react.default.version, app.name, _utils["x-y"]);
+    }, []);
+    const [state, setState] = (0, _react.useState)(0);
+    global.__modules.export("./test", null);
+    "#
+);
+
+test!(
+    Default::default(),
+    |_| plugin(),
+    live_binding_shadowed,
+    // Input codes
+    r#"
+    import { value } from 'module';
+    const obj = { value, nested: { value: value } };
+    function fn(value) {
+        return value;
+    }
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var _module = global.__modules.import("module");
+    const obj = { value: _module.value, nested: { value: _module.value } };
+    function fn(value) {
+        return value;
+    }
+    global.__modules.export("./test", null);
+    "#
+);
+
+test!(
+    Default::default(),
+    |_| plugin(),
+    live_binding_export,
+    // Input codes
+    r#"
+    import { a } from 'module';
+    export { b } from 'module';
+    export { a };
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var _module = global.__modules.import("module");
+    global.__modules.export("./test", {
+        "b": _module.b,
+        "a": _module.a
+    });
+    "#
+);
+
+test!(
+    Syntax::Es(EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |_| plugin(),
+    live_binding_jsx,
+    // Input codes
+    r#"
+    import Header from './Header';
+    import { Container, Text } from '@app/components';
+    <Container>
+        <Header />
+        <Text.Bold>{'Hello'}</Text.Bold>
+    </Container>;
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var _Header = global.__modules.import("./Header");
+    var _components = global.__modules.import("@app/components");
+    <_components.Container>
+        <_Header.default />
+        <_components.Text.Bold>{'Hello'}</_components.Text.Bold>
+    </_components.Container>;
+    global.__modules.export("./test", null);
+    "#
+);
+
+test!(
+    Default::default(),
+    |_| plugin(),
+    live_binding_side_effect_import,
+    // Input codes
+    r#"
+    import './polyfill';
+    import 'react';
+    import { useState } from 'react';
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    global.__modules.import("./polyfill");
+    var _react = global.__modules.import("react");
+    global.__modules.export("./test", null);
+    "#
+);
+
+test!(
+    Syntax::Es(EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |_| plugin(),
+    live_binding_jsx_string_import,
+    // Input codes
+    r#"
+    import { "x-y" as Xy, Text } from '@app/components';
+    <Xy>
+        <Xy.Item />
+        <Text>{Xy.name}</Text>
+    </Xy>;
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var _components = global.__modules.import("@app/components");
+    var Xy = _components["x-y"];
+    <Xy>
+        <Xy.Item />
+        <_components.Text>{_components["x-y"].name}</_components.Text>
+    </Xy>;
+    global.__modules.export("./test", null);
+    "#
+);
+
+test!(
+    Default::default(),
+    |_| plugin(),
+    live_binding_assignment,
+    // Input codes
+    r#"
+    import { a, b, c, d } from 'module';
+    a++;
+    b = 2;
+    ({ c } = {});
+    for (d of []) {
+        console.log(d);
+    }
+    a.value = 1;
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var _module = global.__modules.import("module");
+    (function () { throw new TypeError('"a" is read-only'); })();
+    2, function () { throw new TypeError('"b" is read-only'); }();
+    ({}), function () { throw new TypeError('"c" is read-only'); }();
+    for (var d of []) {
+        (function () { throw new TypeError('"d" is read-only'); })();
+        {
+            console.log(_module.d);
+        }
+    }
+    _module.a.value = 1;
+    global.__modules.export("./test", null);
+    "#
+);
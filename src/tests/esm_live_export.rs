@@ -0,0 +1,134 @@
+use super::{ReactNativeEsbuildModule, ReactNativeEsbuildModuleOptions};
+use swc_core::{
+    common::{chain, Mark},
+    ecma::{
+        transforms::{base::resolver, testing::test},
+        visit::{as_folder, Fold},
+    },
+};
+
+fn plugin(live_bindings: bool) -> impl Fold {
+    let unresolved_mark = Mark::new();
+    chain!(
+        resolver(unresolved_mark, Mark::new(), false),
+        as_folder(ReactNativeEsbuildModule::new(
+            String::from("test.js"),
+            unresolved_mark,
+            ReactNativeEsbuildModuleOptions {
+                runtime_module: Some(true),
+                live_bindings: Some(live_bindings),
+                live_exports: Some(true),
+                ..Default::default()
+            },
+        ))
+    )
+}
+
+test!(
+    Default::default(),
+    |_| plugin(false),
+    live_export,
+    // Input codes
+    r#"
+    import React from 'react';
+    export let counter = 0;
+    export function increase() {
+        counter += 1;
+    }
+    export default class {}
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    global.__modules.export("./test", {
+        get "counter"() {
+            return counter;
+        },
+        get "increase"() {
+            return increase;
+        },
+        get "default"() {
+            return __export_default;
+        }
+    });
+    var React = global.__modules.import("react").default;
+    let counter = 0;
+    function increase() {
+        counter += 1;
+    }
+    var __export_default = class {};
+    "#
+);
+
+test!(
+    Default::default(),
+    |_| plugin(true),
+    live_export_with_live_binding,
+    // Input codes
+    r#"
+    export { value as renamed } from 'module';
+    export * from 'module-all';
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var __exportStar = function (exports, sources) {
+        var hasOwn = Object.prototype.hasOwnProperty;
+        var names = {};
+        sources.forEach(function (source) {
+            source && Object.keys(source).forEach(function (name) {
+                if (name === 'default' || name === '__esModule' || hasOwn.call(exports, name)) {
+                    return;
+                }
+                if (hasOwn.call(names, name)) {
+                    if (names[name] && names[name][name] !== source[name]) {
+                        names[name] = null;
+                    }
+                    return;
+                }
+                names[name] = source;
+            });
+        });
+        Object.keys(names).forEach(function (name) {
+            var source = names[name];
+            source && Object.defineProperty(exports, name, {
+                enumerable: true,
+                get: function () {
+                    return source[name];
+                },
+            });
+        });
+        return exports;
+    };
+    var _module = global.__modules.import("module");
+    var __export_all = global.__modules.import("module-all");
+    global.__modules.export("./test", __exportStar({
+        get "renamed"() {
+            return _module.value;
+        }
+    }, [__export_all]));
+    "#
+);
+
+test!(
+    Default::default(),
+    |_| plugin(false),
+    live_export_before_imports,
+    // Input codes
+    r#"
+    import { b } from './b';
+    export function a() {
+        return b();
+    }
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    global.__modules.export("./test", {
+        get "a"() {
+            return a;
+        }
+    });
+    var b = global.__modules.import("./b").b;
+    function a() {
+        return b();
+    }
+    "#
+);
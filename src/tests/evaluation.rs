@@ -0,0 +1,177 @@
+use super::{harness, ReactNativeEsbuildModuleOptions};
+use std::process::Command;
+
+const PRELUDE: &str = "/app/src/prelude.js";
+
+fn options(factory: bool) -> ReactNativeEsbuildModuleOptions {
+    ReactNativeEsbuildModuleOptions {
+        runtime_module: Some(true),
+        factory: Some(factory),
+        prelude: Some(String::from("src/prelude.js")),
+        root: Some(String::from("/app")),
+        ..Default::default()
+    }
+}
+
+fn live_exports_options() -> ReactNativeEsbuildModuleOptions {
+    ReactNativeEsbuildModuleOptions {
+        live_exports: Some(true),
+        ..options(true)
+    }
+}
+
+fn live_options() -> ReactNativeEsbuildModuleOptions {
+    ReactNativeEsbuildModuleOptions {
+        live_bindings: Some(true),
+        ..live_exports_options()
+    }
+}
+
+fn transform(module_name: &str, code: &str, options: ReactNativeEsbuildModuleOptions) -> String {
+    harness::transform(module_name, code, Default::default(), options).emit()
+}
+
+// Transforms the modules (after the prelude) in the bundle order
+// and returns the output of the bundle that imports `entry` at the end.
+// Each module is scoped by the bundler like esbuild does.
+//
+// Requires Node.js to evaluate the bundle.
+fn evaluate(
+    modules: &[(&str, &str)],
+    entry: &str,
+    options: impl Fn() -> ReactNativeEsbuildModuleOptions,
+) -> String {
+    let mut bundle = transform(PRELUDE, "", options());
+    for (module_name, code) in modules {
+        bundle.push_str(&format!(
+            "(function () {{\n{}}})();\n",
+            transform(module_name, code, options())
+        ));
+    }
+    bundle.push_str(&format!("global.__modules.import({:?});", entry));
+
+    let output = Command::new("node")
+        .arg("-e")
+        .arg(&bundle)
+        .output()
+        .expect("node is required to evaluate the bundle");
+    assert!(
+        output.status.success(),
+        "{}\n{}",
+        bundle,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn evaluate_entry_before_dependencies() {
+    let modules = [
+        (
+            "/app/src/index.js",
+            "import { value } from './value';\nconsole.log(value);",
+        ),
+        ("/app/src/value.js", "export const value = 'value';"),
+    ];
+    assert_eq!(evaluate(&modules, "./src", || options(true)), "value\n");
+}
+
+#[test]
+fn evaluate_entry_after_dependencies() {
+    let modules = [
+        ("/app/src/value.js", "export const value = 'value';"),
+        (
+            "/app/src/index.js",
+            "import { value } from './value';\nconsole.log(value);",
+        ),
+    ];
+    for factory in [false, true] {
+        assert_eq!(evaluate(&modules, "./src", || options(factory)), "value\n");
+    }
+}
+
+#[test]
+fn evaluate_circular_imports() {
+    let modules = [
+        (
+            "/app/src/a.js",
+            "import { b } from './b';\nexport function a() { return 'a'; }\nconsole.log(b());",
+        ),
+        (
+            "/app/src/b.js",
+            "import { a } from './a';\nexport function b() { return 'b:' + a(); }",
+        ),
+    ];
+    assert_eq!(evaluate(&modules, "./src/a", live_options), "b:a\n");
+}
+
+#[test]
+fn evaluate_circular_imports_with_live_exports() {
+    let modules = [
+        (
+            "/app/src/a.js",
+            "import { b } from './b';\nexport function a() { return 'a'; }\nconsole.log(b());",
+        ),
+        (
+            "/app/src/b.js",
+            "import { a } from './a';\nexport function b() { return 'b:' + a(); }",
+        ),
+    ];
+    assert_eq!(evaluate(&modules, "./src/a", live_exports_options), "b:a\n");
+}
+
+#[test]
+fn evaluate_side_effect_import() {
+    let modules = [
+        ("/app/src/polyfill.js", "global.polyfilled = true;"),
+        (
+            "/app/src/index.js",
+            "import './polyfill';\nconsole.log(global.polyfilled);",
+        ),
+    ];
+    for factory in [false, true] {
+        assert_eq!(evaluate(&modules, "./src", || options(factory)), "true\n");
+    }
+}
+
+#[test]
+fn evaluate_hot_invalidate() {
+    let modules = [
+        (
+            "/app/src/counter.js",
+            "const data = import.meta.hot.data;\n\
+             data.count = (data.count || 0) + 1;\n\
+             export const count = data.count;",
+        ),
+        (
+            "/app/src/index.js",
+            "import { count } from './counter';\n\
+             console.log(count);\n\
+             global.__modules.hot('./src/counter').invalidate();\n\
+             console.log(global.__modules.import('./src/counter').count);",
+        ),
+    ];
+    for (factory, expected) in [(false, "1\n1\n"), (true, "1\n2\n")] {
+        let options = || ReactNativeEsbuildModuleOptions {
+            hmr: Some(true),
+            ..options(factory)
+        };
+        assert_eq!(evaluate(&modules, "./src", options), expected);
+    }
+}
+
+#[test]
+fn transform_prelude_module() {
+    for factory in [false, true] {
+        let output = transform(
+            PRELUDE,
+            "import { config } from './config';\nexport const version = config.version;",
+            options(factory),
+        );
+        assert!(output.contains("registry = global[\"__modules\"] = {};"));
+        assert!(output.contains("global.__modules.import(\"./src/config\").config;"));
+        assert!(output.contains("global.__modules.export(\"./src/prelude\""));
+        assert!(!output.contains("global.__modules.define("));
+        assert!(!output.contains("import {"));
+    }
+}
@@ -0,0 +1,130 @@
+use super::{ReactNativeEsbuildModule, ReactNativeEsbuildModuleOptions};
+use std::sync::{Arc, Mutex};
+use swc_core::{
+    common::{
+        chain,
+        comments::{Comments, SingleThreadedComments},
+        errors::{DiagnosticBuilder, Emitter as DiagnosticEmitter, Handler, Level, HANDLER},
+        sync::Lrc,
+        BytePos, FileName, Globals, Mark, SourceMap, Span, GLOBALS,
+    },
+    ecma::{
+        ast::Module,
+        codegen::{text_writer::JsWriter, Config, Emitter},
+        parser::{Parser, StringInput, Syntax},
+        transforms::base::{fixer::fixer, hygiene::hygiene, resolver},
+        visit::{as_folder, FoldWith},
+    },
+};
+
+#[derive(Clone, Default)]
+struct CollectedDiagnostics(Arc<Mutex<Vec<(Level, String, Span)>>>);
+
+impl DiagnosticEmitter for CollectedDiagnostics {
+    fn emit(&mut self, db: &DiagnosticBuilder<'_>) {
+        self.0.lock().unwrap().push((
+            db.level,
+            db.message(),
+            db.span.primary_span().unwrap_or_default(),
+        ));
+    }
+}
+
+/// The module transformed with the plugin and what is reported while transforming it.
+pub struct Transformed {
+    pub module: Module,
+    pub comments: SingleThreadedComments,
+    // `(level, message, span)` of the reported diagnostics
+    pub diagnostics: Vec<(Level, String, Span)>,
+    code: String,
+    start_pos: BytePos,
+    cm: Lrc<SourceMap>,
+    globals: Globals,
+}
+
+impl Transformed {
+    /// Returns the original source code of the span (empty if it's a dummy span).
+    pub fn source(&self, span: Span) -> String {
+        if span.is_dummy() {
+            return String::new();
+        }
+        self.code[(span.lo - self.start_pos).0 as usize..(span.hi - self.start_pos).0 as usize]
+            .to_string()
+    }
+
+    /// Returns the trimmed leading comments at the position.
+    pub fn leading_comments(&self, pos: BytePos) -> Vec<String> {
+        self.comments
+            .get_leading(pos)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|comment| comment.text.trim().to_string())
+            .collect()
+    }
+
+    /// Emits the transformed module (after hygiene and fixer like swc does).
+    pub fn emit(&self) -> String {
+        let module = GLOBALS.set(&self.globals, || {
+            self.module
+                .clone()
+                .fold_with(&mut chain!(hygiene(), fixer(None)))
+        });
+
+        let mut code = Vec::new();
+        Emitter {
+            cfg: Config::default(),
+            cm: self.cm.clone(),
+            comments: None,
+            wr: JsWriter::new(self.cm.clone(), "\n", &mut code, None),
+        }
+        .emit_module(&module)
+        .unwrap();
+        String::from_utf8(code).unwrap()
+    }
+}
+
+/// Parses the code, resolves it and transforms it with the plugin.
+pub fn transform(
+    module_name: &str,
+    code: &str,
+    syntax: Syntax,
+    options: ReactNativeEsbuildModuleOptions,
+) -> Transformed {
+    let cm: Lrc<SourceMap> = Default::default();
+    let fm = cm.new_source_file(FileName::Anon, code.into());
+    let comments = SingleThreadedComments::default();
+    let diagnostics = CollectedDiagnostics::default();
+    let handler = Handler::with_emitter(true, false, Box::new(diagnostics.clone()));
+    let globals = Globals::new();
+
+    let module = GLOBALS.set(&globals, || {
+        HANDLER.set(&handler, || {
+            let unresolved_mark = Mark::new();
+            Parser::new(syntax, StringInput::from(&*fm), Some(&comments))
+                .parse_module()
+                .unwrap()
+                .fold_with(&mut chain!(
+                    resolver(unresolved_mark, Mark::new(), syntax.typescript()),
+                    as_folder(
+                        ReactNativeEsbuildModule::new(
+                            String::from(module_name),
+                            unresolved_mark,
+                            options,
+                        )
+                        .with_comments(comments.clone()),
+                    ),
+                ))
+        })
+    });
+
+    let diagnostics = diagnostics.0.lock().unwrap().clone();
+    Transformed {
+        module,
+        comments,
+        diagnostics,
+        code: code.to_string(),
+        start_pos: fm.start_pos,
+        cm,
+        globals,
+    }
+}
@@ -0,0 +1,136 @@
+use super::{ReactNativeEsbuildModule, ReactNativeEsbuildModuleOptions};
+use swc_core::{
+    common::{chain, Mark},
+    ecma::{
+        transforms::{base::resolver, testing::test},
+        visit::{as_folder, Fold},
+    },
+};
+
+fn plugin(factory: bool) -> impl Fold {
+    let unresolved_mark = Mark::new();
+    chain!(
+        resolver(unresolved_mark, Mark::new(), false),
+        as_folder(ReactNativeEsbuildModule::new(
+            String::from("test.js"),
+            unresolved_mark,
+            ReactNativeEsbuildModuleOptions {
+                runtime_module: Some(true),
+                factory: Some(factory),
+                commonjs: Some(true),
+                hmr: Some(true),
+                ..Default::default()
+            },
+        ))
+    )
+}
+
+test!(
+    Default::default(),
+    |_| plugin(false),
+    hmr_import_meta_hot,
+    // Input codes
+    r#"
+    import { render } from './render';
+    export const value = 1;
+    if (import.meta.hot) {
+        import.meta.hot.accept((module) => render(module.value));
+    }
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var __hot = global.__modules.hot("./test");
+    var render = global.__modules.import("./render").render;
+    const value = 1;
+    if (__hot) {
+        __hot.accept((module) => render(module.value));
+    }
+    global.__modules.export("./test", { "value": value });
+    "#
+);
+
+test!(
+    Default::default(),
+    |_| plugin(false),
+    hmr_module_hot,
+    // Input codes
+    r#"
+    const App = require('./App');
+    if (module.hot) {
+        module.hot.dispose((data) => {
+            data.count = App.count;
+        });
+    }
+    module.exports = App;
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var __hot = global.__modules.hot("./test");
+    var __module = { exports: {} };
+    var __exports = __module.exports;
+    const App = global.__modules.import("./App");
+    if (__hot) {
+        __hot.dispose((data) => {
+            data.count = App.count;
+        });
+    }
+    __module.exports = App;
+    global.__modules.export("./test", __module.exports);
+    "#
+);
+
+test!(
+    Default::default(),
+    |_| plugin(false),
+    hmr_local_module_binding,
+    // Input codes
+    r#"
+    export function getHot(module) {
+        return module.hot;
+    }
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var __hot = global.__modules.hot("./test");
+    function getHot(module) {
+        return module.hot;
+    }
+    global.__modules.export("./test", { "getHot": getHot });
+    "#
+);
+
+test!(
+    Default::default(),
+    |_| plugin(true),
+    hmr_factory,
+    // Input codes
+    r#"
+    export const a = 1;
+    import.meta.hot.accept();
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    global.__modules.define("./test", [], function () {
+        var __hot = global.__modules.hot("./test");
+        const a = 1;
+        __hot.accept();
+        global.__modules.export("./test", { "a": a });
+    });
+    "#
+);
+
+test!(
+    Default::default(),
+    |_| plugin(false),
+    hmr_side_effect_module,
+    // Input codes
+    r#"
+    console.log('script');
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var __hot = global.__modules.hot("./test");
+    console.log('script');
+    global.__modules.export("./test", null);
+    "#
+);
@@ -0,0 +1,196 @@
+use super::{ReactNativeEsbuildModule, ReactNativeEsbuildModuleOptions};
+use swc_core::{
+    common::Mark,
+    ecma::{
+        parser::{EsConfig, Syntax},
+        transforms::testing::test,
+        visit::{as_folder, Folder},
+    },
+};
+
+fn syntax() -> Syntax {
+    Syntax::Es(EsConfig {
+        import_attributes: true,
+        ..Default::default()
+    })
+}
+
+fn plugin() -> Folder<ReactNativeEsbuildModule> {
+    as_folder(ReactNativeEsbuildModule::new(
+        String::from("test.js"),
+        Mark::new(),
+        ReactNativeEsbuildModuleOptions {
+            runtime_module: Some(true),
+            ..Default::default()
+        },
+    ))
+}
+
+fn live_bindings_plugin() -> Folder<ReactNativeEsbuildModule> {
+    as_folder(ReactNativeEsbuildModule::new(
+        String::from("test.js"),
+        Mark::new(),
+        ReactNativeEsbuildModuleOptions {
+            runtime_module: Some(true),
+            live_bindings: Some(true),
+            ..Default::default()
+        },
+    ))
+}
+
+fn inline_json_plugin(live_bindings: bool) -> Folder<ReactNativeEsbuildModule> {
+    let fixtures = concat!(env!("CARGO_MANIFEST_DIR"), "/src/tests/fixtures");
+    as_folder(ReactNativeEsbuildModule::new(
+        format!("{}/test.js", fixtures),
+        Mark::new(),
+        ReactNativeEsbuildModuleOptions {
+            runtime_module: Some(true),
+            live_bindings: Some(live_bindings),
+            inline_json: Some(true),
+            root: Some(String::from(fixtures)),
+            ..Default::default()
+        },
+    ))
+}
+
+test!(
+    syntax(),
+    |_| plugin(),
+    import_with_attributes,
+    // Input codes
+    r#"
+    import config from './config.json' with { type: 'json' };
+    import * as manifest from './manifest.json' with { type: 'json' };
+    import { a } from 'module';
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var config = global.__modules.import("./config.json", { type: 'json' }).default;
+    var manifest = global.__modules.import("./manifest.json", { type: 'json' });
+    var a = global.__modules.import("module").a;
+    global.__modules.export("./test", null);
+    "#
+);
+
+test!(
+    syntax(),
+    |_| plugin(),
+    re_export_with_attributes,
+    // Input codes
+    r#"
+    export { default as config } from './config.json' with { type: 'json' };
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var __export_default = global.__modules.import("./config.json", { type: 'json' }).default;
+    global.__modules.export("./test", { "config": __export_default });
+    "#
+);
+
+test!(
+    syntax(),
+    |_| inline_json_plugin(false),
+    inline_json_import,
+    // Input codes
+    r#"
+    import config from './config.json' with { type: 'json' };
+    import * as ns from './config.json' with { type: 'json' };
+    import missing from './missing.json' with { type: 'json' };
+    import data from './config.json';
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var config = {
+        "name": "app",
+        "version": 1,
+        "features": ["live", true, null]
+    };
+    var ns = {
+        default: {
+            "name": "app",
+            "version": 1,
+            "features": ["live", true, null]
+        }
+    };
+    var missing = global.__modules.import("./missing.json", { type: 'json' }).default;
+    var data = global.__modules.import("./config.json").default;
+    global.__modules.export("./test", null);
+    "#
+);
+
+test!(
+    syntax(),
+    |_| inline_json_plugin(true),
+    inline_json_import_with_live_bindings,
+    // Input codes
+    r#"
+    import config from './config.json' with { type: 'json' };
+    console.log(config.name);
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var config = {
+        "name": "app",
+        "version": 1,
+        "features": ["live", true, null]
+    };
+    console.log(config.name);
+    global.__modules.export("./test", null);
+    "#
+);
+
+test!(
+    syntax(),
+    |_| plugin(),
+    same_module_with_and_without_attributes,
+    // Input codes
+    r#"
+    import data from './data.json' with { type: 'json' };
+    import raw from './data.json';
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var data = global.__modules.import("./data.json", { type: 'json' }).default;
+    var raw = global.__modules.import("./data.json").default;
+    global.__modules.export("./test", null);
+    "#
+);
+
+test!(
+    syntax(),
+    |_| live_bindings_plugin(),
+    live_binding_same_module_with_and_without_attributes,
+    // Input codes
+    r#"
+    import data from './data.json' with { type: 'json' };
+    import raw from './data.json';
+    console.log(data, raw);
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var _data_json = global.__modules.import("./data.json", { type: 'json' });
+    var _data_json1 = global.__modules.import("./data.json");
+    console.log(_data_json.default, _data_json1.default);
+    global.__modules.export("./test", null);
+    "#
+);
+
+test!(
+    syntax(),
+    |_| inline_json_plugin(false),
+    inline_json_proto_key,
+    // Input codes
+    r#"
+    import proto from './proto.json' with { type: 'json' };
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var proto = {
+        ["__proto__"]: {
+            "polluted": true
+        },
+        "name": "proto"
+    };
+    global.__modules.export("./test", null);
+    "#
+);
@@ -0,0 +1,132 @@
+use super::{ReactNativeEsbuildModule, ReactNativeEsbuildModuleOptions};
+use swc_core::{
+    common::Mark,
+    ecma::{
+        transforms::testing::test,
+        visit::{as_folder, Folder},
+    },
+};
+
+fn plugin(runtime_module: bool) -> Folder<ReactNativeEsbuildModule> {
+    as_folder(ReactNativeEsbuildModule::new(
+        String::from("/app/src/App.tsx"),
+        Mark::new(),
+        ReactNativeEsbuildModuleOptions {
+            runtime_module: Some(runtime_module),
+            factory: Some(true),
+            ..Default::default()
+        },
+    ))
+}
+
+test!(
+    Default::default(),
+    |_| plugin(true),
+    factory_module,
+    // Input codes
+    r#"
+    import './polyfill';
+    import React, { useState } from 'react';
+    import { Button } from './components/Button';
+    export * from './utils';
+    export function App() {
+        return null;
+    }
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    global.__modules.define("/app/src/App", ["/app/src/polyfill", "react", "/app/src/components/Button", "/app/src/utils"], function () {
+        var __exportStar = function (exports, sources) {
+            var hasOwn = Object.prototype.hasOwnProperty;
+            var names = {};
+            sources.forEach(function (source) {
+                source && Object.keys(source).forEach(function (name) {
+                    if (name === 'default' || name === '__esModule' || hasOwn.call(exports, name)) {
+                        return;
+                    }
+                    if (hasOwn.call(names, name)) {
+                        if (names[name] && names[name][name] !== source[name]) {
+                            names[name] = null;
+                        }
+                        return;
+                    }
+                    names[name] = source;
+                });
+            });
+            Object.keys(names).forEach(function (name) {
+                var source = names[name];
+                source && Object.defineProperty(exports, name, {
+                    enumerable: true,
+                    get: function () {
+                        return source[name];
+                    },
+                });
+            });
+            return exports;
+        };
+        global.__modules.import("/app/src/polyfill");
+        var _react = global.__modules.import("react");
+        var React = _react.default;
+        var useState = _react.useState;
+        var Button = global.__modules.import("/app/src/components/Button").Button;
+        var __export_all = global.__modules.import("/app/src/utils");
+        function App() {
+            return null;
+        }
+        global.__modules.export("/app/src/App", __exportStar({
+            "App": App
+        }, [__export_all]));
+    });
+    "#
+);
+
+test!(
+    Default::default(),
+    |_| plugin(true),
+    factory_side_effect_module,
+    // Input codes
+    r#"
+    console.log('script');
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    global.__modules.define("/app/src/App", [], function () {
+        console.log('script');
+        global.__modules.export("/app/src/App", null);
+    });
+    "#
+);
+
+test!(
+    Default::default(),
+    |_| plugin(false),
+    factory_bundle_time,
+    // Input codes
+    r#"
+    import React from 'react';
+    export const value = React.version;
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    import React from 'react';
+    export const value = React.version;
+    global.__modules.export("/app/src/App", { "value": value });
+    "#
+);
+
+test!(
+    Default::default(),
+    |_| plugin(true),
+    factory_top_level_await,
+    // Input codes
+    r#"
+    import { load } from './loader';
+    export const value = await load();
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var load = global.__modules.import("/app/src/loader").load;
+    const value = await load();
+    global.__modules.export("/app/src/App", { "value": value });
+    "#
+);
@@ -0,0 +1,187 @@
+use super::{ModuleIdStrategy, ReactNativeEsbuildModule, ReactNativeEsbuildModuleOptions};
+use std::collections::HashMap;
+use swc_core::{
+    common::Mark,
+    ecma::{
+        transforms::testing::test,
+        visit::{as_folder, Folder},
+    },
+};
+
+fn plugin_with_module_id(
+    module_id: ModuleIdStrategy,
+    module_id_map: Option<HashMap<String, u32>>,
+) -> Folder<ReactNativeEsbuildModule> {
+    as_folder(ReactNativeEsbuildModule::new(
+        String::from("/app/src/screens/Home.ios.tsx"),
+        Mark::new(),
+        ReactNativeEsbuildModuleOptions {
+            runtime_module: Some(true),
+            root: Some(String::from("/app")),
+            module_id: Some(module_id),
+            module_id_map,
+            ..Default::default()
+        },
+    ))
+}
+
+fn plugin() -> Folder<ReactNativeEsbuildModule> {
+    as_folder(ReactNativeEsbuildModule::new(
+        String::from("/app/src/screens/Home.ios.tsx"),
+        Mark::new(),
+        ReactNativeEsbuildModuleOptions {
+            runtime_module: Some(true),
+            ..Default::default()
+        },
+    ))
+}
+
+test!(
+    Default::default(),
+    |_| plugin(),
+    resolve_relative_import,
+    // Input codes
+    r#"
+    import Header from './Header';
+    import { Button } from '../components/Button.tsx';
+    import * as hooks from '../hooks/index';
+    export * from '../../shared/utils.js';
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var __exportStar = function (exports, sources) {
+        var hasOwn = Object.prototype.hasOwnProperty;
+        var names = {};
+        sources.forEach(function (source) {
+            source && Object.keys(source).forEach(function (name) {
+                if (name === 'default' || name === '__esModule' || hasOwn.call(exports, name)) {
+                    return;
+                }
+                if (hasOwn.call(names, name)) {
+                    if (names[name] && names[name][name] !== source[name]) {
+                        names[name] = null;
+                    }
+                    return;
+                }
+                names[name] = source;
+            });
+        });
+        Object.keys(names).forEach(function (name) {
+            var source = names[name];
+            source && Object.defineProperty(exports, name, {
+                enumerable: true,
+                get: function () {
+                    return source[name];
+                },
+            });
+        });
+        return exports;
+    };
+    var Header = global.__modules.import("/app/src/screens/Header").default;
+    var Button = global.__modules.import("/app/src/components/Button").Button;
+    var hooks = global.__modules.import("/app/src/hooks");
+    var __export_all = global.__modules.import("/app/shared/utils");
+    global.__modules.export("/app/src/screens/Home", __exportStar({}, [__export_all]));
+    "#
+);
+
+test!(
+    Default::default(),
+    |_| plugin(),
+    keep_package_import,
+    // Input codes
+    r#"
+    import React from 'react';
+    import { Text } from 'react-native';
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var React = global.__modules.import("react").default;
+    var Text = global.__modules.import("react-native").Text;
+    global.__modules.export("/app/src/screens/Home", null);
+    "#
+);
+
+test!(
+    Default::default(),
+    |_| plugin(),
+    strip_module_extensions,
+    // Input codes
+    r#"
+    import { format } from './format.mjs';
+    import { parse } from './parse.mts';
+    import { legacy } from './legacy.cts';
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var format = global.__modules.import("/app/src/screens/format").format;
+    var parse = global.__modules.import("/app/src/screens/parse").parse;
+    var legacy = global.__modules.import("/app/src/screens/legacy").legacy;
+    global.__modules.export("/app/src/screens/Home", null);
+    "#
+);
+
+test!(
+    Default::default(),
+    |_| plugin_with_module_id(ModuleIdStrategy::Path, None),
+    module_id_path,
+    // Input codes
+    r#"
+    import React from 'react';
+    import { Button } from '../components/Button';
+    import LocalReact from '../../react';
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var React = global.__modules.import("react").default;
+    var Button = global.__modules.import("./src/components/Button").Button;
+    var LocalReact = global.__modules.import("./react").default;
+    global.__modules.export("./src/screens/Home", null);
+    "#
+);
+
+test!(
+    Default::default(),
+    |_| plugin_with_module_id(ModuleIdStrategy::Hash, None),
+    module_id_hash,
+    // Input codes
+    r#"
+    import React from 'react';
+    import { Button } from '../components/Button';
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var React = global.__modules.import("react").default;
+    var Button = global.__modules.import("6a0be0d3").Button;
+    global.__modules.export("f325fdbf", null);
+    "#
+);
+
+test!(
+    Default::default(),
+    |_| plugin_with_module_id(
+        ModuleIdStrategy::Numeric,
+        Some(HashMap::from([
+            (String::from("react"), 0),
+            (String::from("src/screens/Home.tsx"), 1),
+            (String::from("./src/components/Button"), 2),
+            (String::from("chart.js"), 3),
+        ]))
+    ),
+    module_id_numeric,
+    // Input codes
+    r#"
+    import React from 'react';
+    import { Button } from '../components/Button';
+    import { Text } from 'react-native';
+    import Chart from 'chart.js';
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var React = global.__modules.import(0).default;
+    var Button = global.__modules.import(2).Button;
+    var Text = global.__modules.import("react-native").Text;
+    var Chart = global.__modules.import(3).default;
+    global.__modules.export(1, null);
+    "#
+);
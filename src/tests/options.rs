@@ -0,0 +1,75 @@
+use super::parse_options;
+use crate::module_id::ModuleIdStrategy;
+
+#[test]
+fn empty_config() {
+    for config in ["", "  ", "null", "{}"] {
+        let options = parse_options(config).unwrap();
+        assert_eq!(options.runtime_module, None);
+        assert_eq!(options.module_id, None);
+    }
+}
+
+#[test]
+fn valid_config() {
+    let options =
+        parse_options(r#"{ "runtimeModule": true, "moduleId": "hash", "root": "/app" }"#).unwrap();
+    assert_eq!(options.runtime_module, Some(true));
+    assert_eq!(options.module_id, Some(ModuleIdStrategy::Hash));
+    assert_eq!(options.root, Some(String::from("/app")));
+}
+
+#[test]
+fn unknown_option() {
+    let error = parse_options(r#"{ "runtimeModul": true }"#).err().unwrap();
+    assert!(error.starts_with("`runtimeModul`: unknown field `runtimeModul`, expected one of"));
+}
+
+#[test]
+fn invalid_option_type() {
+    let error = parse_options(r#"{ "factory": true, "liveBindings": "yes" }"#)
+        .err()
+        .unwrap();
+    assert!(error.starts_with(r#"`liveBindings`: invalid type: string "yes", expected a boolean"#));
+
+    let error = parse_options(r#"{ "moduleId": "uuid" }"#).err().unwrap();
+    assert!(error.starts_with("`moduleId`: unknown variant `uuid`"));
+}
+
+#[test]
+fn invalid_config() {
+    assert_eq!(
+        parse_options("[]").err().unwrap(),
+        "expected an object, found `[]`"
+    );
+    assert!(parse_options("{ runtimeModule: true }")
+        .err()
+        .unwrap()
+        .starts_with("invalid JSON: "));
+}
+
+#[test]
+fn registry_names() {
+    let options = parse_options(
+        r#"{ "globalObject": "self", "registryName": "__app", "methodNames": { "import": "require" } }"#,
+    )
+    .unwrap();
+    assert_eq!(options.global_object, Some(String::from("self")));
+    assert_eq!(options.registry_name, Some(String::from("__app")));
+    let method_names = options.method_names.unwrap();
+    assert_eq!(method_names.import, "require");
+    assert_eq!(method_names.export, "export");
+
+    let error = parse_options(r#"{ "globalObject": "window.app" }"#)
+        .err()
+        .unwrap();
+    assert_eq!(
+        error,
+        r#"`globalObject`: expected an identifier, found "window.app""#
+    );
+
+    let error = parse_options(r#"{ "methodNames": { "require": "r" } }"#)
+        .err()
+        .unwrap();
+    assert!(error.starts_with("`methodNames`: unknown field `require`"));
+}
@@ -0,0 +1,150 @@
+use super::{
+    ReactNativeEsbuildModule, ReactNativeEsbuildModuleOptions, RegistryMethodNames, Target,
+};
+use swc_core::{
+    common::Mark,
+    ecma::{
+        transforms::testing::test,
+        visit::{as_folder, Folder},
+    },
+};
+
+fn plugin(factory: bool) -> Folder<ReactNativeEsbuildModule> {
+    as_folder(ReactNativeEsbuildModule::new(
+        String::from("test.js"),
+        Mark::new(),
+        ReactNativeEsbuildModuleOptions {
+            runtime_module: Some(true),
+            factory: Some(factory),
+            global_object: Some(String::from("globalThis")),
+            registry_name: Some(String::from("__plugin-modules")),
+            method_names: Some(RegistryMethodNames {
+                import: String::from("require"),
+                export: String::from("register"),
+                import_async: String::from("load"),
+                define: String::from("module"),
+                hot: String::from("context"),
+            }),
+            ..Default::default()
+        },
+    ))
+}
+
+fn web_plugin(factory: bool) -> Folder<ReactNativeEsbuildModule> {
+    as_folder(ReactNativeEsbuildModule::new(
+        String::from("test.js"),
+        Mark::new(),
+        ReactNativeEsbuildModuleOptions {
+            runtime_module: Some(true),
+            factory: Some(factory),
+            target: Some(Target::Web),
+            ..Default::default()
+        },
+    ))
+}
+
+test!(
+    Default::default(),
+    |_| plugin(false),
+    custom_registry,
+    // Input codes
+    r#"
+    import React from 'react';
+    const Settings = React.lazy(() => import('./Settings'));
+    export default Settings;
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var React = globalThis["__plugin-modules"].require("react").default;
+    const Settings = React.lazy(() => globalThis["__plugin-modules"].load("./Settings"));
+    var __export_default = Settings;
+    globalThis["__plugin-modules"].register("./test", { "default": __export_default });
+    "#
+);
+
+test!(
+    Default::default(),
+    |_| plugin(true),
+    custom_registry_factory,
+    // Input codes
+    r#"
+    export const a = 1;
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    globalThis["__plugin-modules"].module("./test", [], function () {
+        const a = 1;
+        globalThis["__plugin-modules"].register("./test", { "a": a });
+    });
+    "#
+);
+
+test!(
+    Default::default(),
+    |_| web_plugin(false),
+    web_target,
+    // Input codes
+    r#"
+    import React from 'react';
+    export default React.lazy(() => import('./Settings'));
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var __global = typeof globalThis !== 'undefined'
+        ? globalThis
+        : typeof self !== 'undefined'
+        ? self
+        : typeof window !== 'undefined'
+        ? window
+        : global;
+    var React = __global.__modules.import("react").default;
+    var __export_default = React.lazy(() => __global.__modules.importAsync("./Settings"));
+    __global.__modules.export("./test", { "default": __export_default });
+    "#
+);
+
+test!(
+    Default::default(),
+    |_| web_plugin(true),
+    web_target_factory,
+    // Input codes
+    r#"
+    export const a = 1;
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var __global = typeof globalThis !== 'undefined'
+        ? globalThis
+        : typeof self !== 'undefined'
+        ? self
+        : typeof window !== 'undefined'
+        ? window
+        : global;
+    __global.__modules.define("./test", [], function () {
+        const a = 1;
+        __global.__modules.export("./test", { "a": a });
+    });
+    "#
+);
+
+test!(
+    Default::default(),
+    |_| web_plugin(false),
+    web_target_side_effect_module,
+    // Input codes
+    r#"
+    const a = 1;
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var __global = typeof globalThis !== 'undefined'
+        ? globalThis
+        : typeof self !== 'undefined'
+        ? self
+        : typeof window !== 'undefined'
+        ? window
+        : global;
+    const a = 1;
+    __global.__modules.export("./test", null);
+    "#
+);
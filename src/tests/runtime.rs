@@ -0,0 +1,138 @@
+use super::{ReactNativeEsbuildModule, ReactNativeEsbuildModuleOptions};
+use swc_core::{
+    common::Mark,
+    ecma::{
+        transforms::testing::test,
+        visit::{as_folder, Folder},
+    },
+};
+
+fn plugin(module_name: &str) -> Folder<ReactNativeEsbuildModule> {
+    as_folder(ReactNativeEsbuildModule::new(
+        String::from(module_name),
+        Mark::new(),
+        ReactNativeEsbuildModuleOptions {
+            runtime_module: Some(true),
+            prelude: Some(String::from("src/prelude.js")),
+            root: Some(String::from("/app")),
+            ..Default::default()
+        },
+    ))
+}
+
+test!(
+    Default::default(),
+    |_| plugin("/app/src/prelude.js"),
+    inject_runtime_to_prelude,
+    // Input codes
+    r#"
+    global.__DEV__ = true;
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    (function (global) {
+        var registry = global["__modules"];
+        if (registry) {
+            return;
+        }
+        var hasOwn = Object.prototype.hasOwnProperty;
+        var modules = {};
+        var factories = {};
+        var evaluating = {};
+        var hotContexts = {};
+        var hotCallbacks = {};
+        var hotData = {};
+        registry = global["__modules"] = {};
+        registry["import"] = function (id) {
+            if (hasOwn.call(modules, id)) {
+                return modules[id];
+            }
+            if (hasOwn.call(factories, id)) {
+                var factory = factories[id];
+                // Register the exports before evaluating the module
+                // so that circular imports get the partial exports.
+                evaluating[id] = modules[id] = {};
+                try {
+                    factory();
+                } catch (error) {
+                    delete modules[id];
+                    throw error;
+                } finally {
+                    delete evaluating[id];
+                }
+                return modules[id];
+            }
+            throw new Error('"' + id + '" module not found');
+        };
+        registry["export"] = function (id, exports) {
+            var partialExports = evaluating[id];
+            if (partialExports && partialExports === modules[id] && exports && typeof exports === 'object') {
+                Object.getOwnPropertyNames(exports).forEach(function (name) {
+                    Object.defineProperty(partialExports, name, Object.getOwnPropertyDescriptor(exports, name));
+                });
+                return partialExports;
+            }
+            return modules[id] = exports;
+        };
+        registry["importAsync"] = function (id) {
+            return new Promise(function (resolve) {
+                resolve(registry["import"](id));
+            });
+        };
+        registry["define"] = function (id, deps, factory) {
+            factories[id] = factory;
+            if (hasOwn.call(hotContexts, id)) {
+                var acceptCallbacks = hotCallbacks[id].accept;
+                hotContexts[id].invalidate();
+                var exports = registry["import"](id);
+                acceptCallbacks.forEach(function (callback) {
+                    callback(exports);
+                });
+            }
+        };
+        registry["hot"] = function (id) {
+            if (hasOwn.call(hotContexts, id)) {
+                return hotContexts[id];
+            }
+            var callbacks = hotCallbacks[id] = { accept: [], dispose: [] };
+            var data = hasOwn.call(hotData, id) ? hotData[id] : hotData[id] = {};
+            return hotContexts[id] = {
+                data: data,
+                accept: function (callback) {
+                    typeof callback === 'function' && callbacks.accept.push(callback);
+                },
+                dispose: function (callback) {
+                    callbacks.dispose.push(callback);
+                },
+                invalidate: function () {
+                    delete hotContexts[id];
+                    // The module is evaluated again on the next import.
+                    if (hasOwn.call(factories, id)) {
+                        delete modules[id];
+                    }
+                    callbacks.dispose.forEach(function (callback) {
+                        callback(data);
+                    });
+                },
+            };
+        };
+    })(global);
+    global.__DEV__ = true;
+    global.__modules.export("./src/prelude", null);
+    "#
+);
+
+test!(
+    Default::default(),
+    |_| plugin("/app/src/App.js"),
+    skip_runtime_of_non_prelude,
+    // Input codes
+    r#"
+    export default function App() {}
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    function App() {}
+    global.__modules.export("./src/App", { "default": App });
+    "#
+);
@@ -0,0 +1,50 @@
+use super::{harness, ReactNativeEsbuildModuleOptions};
+use swc_core::common::Spanned;
+
+// Returns the original source code of each generated statement (empty if it has no span).
+fn transform(code: &str) -> Vec<String> {
+    let transformed = harness::transform(
+        "test.js",
+        code,
+        Default::default(),
+        ReactNativeEsbuildModuleOptions {
+            runtime_module: Some(true),
+            ..Default::default()
+        },
+    );
+    transformed
+        .module
+        .body
+        .iter()
+        .map(|module_item| transformed.source(module_item.span()))
+        .collect()
+}
+
+#[test]
+fn import_and_export_spans() {
+    assert_eq!(
+        transform(
+            "import React, { useState } from 'react';\n\
+             import { a } from './a';\n\
+             export const b = 1;\n\
+             export default a;"
+        ),
+        vec![
+            "React",
+            "React",
+            "useState",
+            "a",
+            "const b = 1;",
+            "export default a;",
+            "b",
+        ]
+    );
+}
+
+#[test]
+fn side_effect_import_span() {
+    assert_eq!(
+        transform("import './polyfill';"),
+        vec!["import './polyfill';", ""]
+    );
+}
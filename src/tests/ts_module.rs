@@ -0,0 +1,212 @@
+use super::{ReactNativeEsbuildModule, ReactNativeEsbuildModuleOptions};
+use swc_core::{
+    common::Mark,
+    ecma::{
+        parser::{Syntax, TsConfig},
+        transforms::testing::test,
+        visit::{as_folder, Folder},
+    },
+};
+
+fn syntax() -> Syntax {
+    Syntax::Typescript(TsConfig::default())
+}
+
+fn plugin(runtime_module: bool) -> Folder<ReactNativeEsbuildModule> {
+    as_folder(ReactNativeEsbuildModule::new(
+        String::from("test.ts"),
+        Mark::new(),
+        ReactNativeEsbuildModuleOptions {
+            runtime_module: Some(runtime_module),
+            ..Default::default()
+        },
+    ))
+}
+
+fn factory_plugin() -> Folder<ReactNativeEsbuildModule> {
+    as_folder(ReactNativeEsbuildModule::new(
+        String::from("test.ts"),
+        Mark::new(),
+        ReactNativeEsbuildModuleOptions {
+            runtime_module: Some(true),
+            factory: Some(true),
+            ..Default::default()
+        },
+    ))
+}
+
+test!(
+    syntax(),
+    |_| plugin(true),
+    export_enum_decl,
+    // Input codes
+    r#"
+    export enum Direction { Up, Down }
+    export const enum Color { Red = 'red' }
+    export declare enum Ambient { A }
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    enum Direction { Up, Down }
+    const enum Color { Red = 'red' }
+    declare enum Ambient { A }
+    global.__modules.export("./test", { "Direction": Direction, "Color": Color });
+    "#
+);
+
+test!(
+    syntax(),
+    |_| plugin(true),
+    export_namespace_decl,
+    // Input codes
+    r#"
+    export namespace Utils {
+      export const value = 1;
+    }
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    namespace Utils {
+      export const value = 1;
+    }
+    global.__modules.export("./test", { "Utils": Utils });
+    "#
+);
+
+test!(
+    syntax(),
+    |_| plugin(true),
+    import_equals_require,
+    // Input codes
+    r#"
+    import React = require('react');
+    export import Native = require('react-native');
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var React = global.__modules.import("react");
+    var Native = global.__modules.import("react-native");
+    global.__modules.export("./test", { "Native": Native });
+    "#
+);
+
+test!(
+    syntax(),
+    |_| plugin(true),
+    export_import_equals_entity,
+    // Input codes
+    r#"
+    namespace Utils {
+      export const value = 1;
+    }
+    export import value = Utils.value;
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    namespace Utils {
+      export const value = 1;
+    }
+    var value = Utils.value;
+    global.__modules.export("./test", { "value": value });
+    "#
+);
+
+test!(
+    syntax(),
+    |_| plugin(true),
+    export_assignment,
+    // Input codes
+    r#"
+    import { create } from 'factory';
+    export = create();
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var create = global.__modules.import("factory").create;
+    var __export_assignment = create();
+    global.__modules.export("./test", __export_assignment);
+    "#
+);
+
+test!(
+    syntax(),
+    |_| plugin(false),
+    bundle_time_export_assignment,
+    // Input codes
+    r#"
+    import React = require('react');
+    export = React;
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    import React = require('react');
+    var __export_assignment = React;
+    export = __export_assignment;
+    global.__modules.export("./test", __export_assignment);
+    "#
+);
+
+test!(
+    syntax(),
+    |_| plugin(true),
+    type_only_import_and_export,
+    // Input codes
+    r#"
+    import type { Props } from './types';
+    import { type State, useState } from 'react';
+    import type * as Types from './types';
+    export type { Props };
+    export { type State };
+    export type { Theme } from './theme';
+    export { type Config, config } from './config';
+    export type * from './types';
+    export default interface DefaultProps {}
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var useState = global.__modules.import("react").useState;
+    var config = global.__modules.import("./config").config;
+    global.__modules.export("./test", { "config": config });
+    "#
+);
+
+test!(
+    syntax(),
+    |_| plugin(false),
+    bundle_time_type_only_import_and_export,
+    // Input codes
+    r#"
+    import type { Props } from './types';
+    export type { Props };
+    export { type Config, config } from './config';
+    export default interface DefaultProps {}
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    var config = global.__modules.import("./config").config;
+    import type { Props } from './types';
+    export type { Props };
+    export { type Config, config } from './config';
+    export default interface DefaultProps {}
+    global.__modules.export("./test", { "config": config });
+    "#
+);
+
+test!(
+    syntax(),
+    |_| factory_plugin(),
+    import_equals_alias_factory,
+    // Input codes
+    r#"
+    import c = N.a.b;
+    export const d = c;
+    "#,
+    // Output codes after transformed with plugin
+    r#"
+    global.__modules.define("./test", [], function () {
+        var c = N.a.b;
+        const d = c;
+        global.__modules.export("./test", { "d": d });
+    });
+    "#
+);
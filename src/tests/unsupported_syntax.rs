@@ -0,0 +1,164 @@
+use super::{harness, ModuleIdStrategy, ReactNativeEsbuildModuleOptions};
+use std::collections::HashMap;
+use swc_core::{
+    common::errors::Level,
+    ecma::parser::{Syntax, TsConfig},
+};
+
+fn transform(code: &str, strict: bool) -> Vec<(Level, String, String)> {
+    transform_with_options(
+        code,
+        ReactNativeEsbuildModuleOptions {
+            runtime_module: Some(true),
+            commonjs: Some(true),
+            strict: Some(strict),
+            ..Default::default()
+        },
+    )
+}
+
+// Returns `(level, message, source code of the span)` of the reported diagnostics.
+fn transform_with_options(
+    code: &str,
+    options: ReactNativeEsbuildModuleOptions,
+) -> Vec<(Level, String, String)> {
+    let transformed = harness::transform(
+        "test.ts",
+        code,
+        Syntax::Typescript(TsConfig::default()),
+        options,
+    );
+    transformed
+        .diagnostics
+        .iter()
+        .map(|(level, message, span)| (*level, message.clone(), transformed.source(*span)))
+        .collect()
+}
+
+#[test]
+fn no_diagnostics() {
+    assert!(transform(
+        "import a from 'a'; import('./b'); require('c'); export { a };",
+        false
+    )
+    .is_empty());
+}
+
+#[test]
+fn non_literal_dynamic_import() {
+    assert_eq!(
+        transform("import(`./screens/${name}`);", false),
+        vec![(
+            Level::Warning,
+            String::from("swc-plugin-react-native-esbuild-module: `import()` without a string literal is not transformed"),
+            String::from("import(`./screens/${name}`)"),
+        )]
+    );
+}
+
+#[test]
+fn non_literal_require() {
+    assert_eq!(
+        transform("const a = require(name);", false),
+        vec![(
+            Level::Warning,
+            String::from("swc-plugin-react-native-esbuild-module: `require()` without a string literal is not transformed"),
+            String::from("require(name)"),
+        )]
+    );
+}
+
+#[test]
+fn module_exports_in_es_module() {
+    assert_eq!(
+        transform("export const a = 1;\nmodule.exports.b = 2;", false),
+        vec![(
+            Level::Warning,
+            String::from("swc-plugin-react-native-esbuild-module: `module` and `exports` of ES modules are not registered"),
+            String::from("module"),
+        )]
+    );
+}
+
+#[test]
+fn export_assignment_with_other_exports() {
+    assert_eq!(
+        transform("export const a = 1;\nexport = a;", false),
+        vec![(
+            Level::Warning,
+            String::from("swc-plugin-react-native-esbuild-module: `export =` replaces the whole exports, other exports of the module are not registered"),
+            String::from("export = a;"),
+        )]
+    );
+}
+
+#[test]
+fn strict() {
+    assert_eq!(
+        transform("import(name);", true),
+        vec![(
+            Level::Error,
+            String::from("swc-plugin-react-native-esbuild-module: `import()` without a string literal is not transformed"),
+            String::from("import(name)"),
+        )]
+    );
+}
+
+#[test]
+fn missing_numeric_module_id() {
+    let options = |strict| ReactNativeEsbuildModuleOptions {
+        runtime_module: Some(true),
+        module_id: Some(ModuleIdStrategy::Numeric),
+        module_id_map: Some(HashMap::from([(String::from("test.ts"), 1)])),
+        strict: Some(strict),
+        ..Default::default()
+    };
+    for (strict, level) in [(false, Level::Warning), (true, Level::Error)] {
+        assert_eq!(
+            transform_with_options("import a from 'a';\nimport b from 'a';", options(strict)),
+            vec![(
+                level,
+                String::from("swc-plugin-react-native-esbuild-module: `a` is not in `moduleIdMap`, the module path is used as the module id"),
+                String::from("a"),
+            )]
+        );
+    }
+}
+
+#[test]
+fn top_level_await_in_factory() {
+    assert_eq!(
+        transform_with_options(
+            "import { x } from './x';\nconst v = await x.load();\nexport { v };",
+            ReactNativeEsbuildModuleOptions {
+                runtime_module: Some(true),
+                factory: Some(true),
+                ..Default::default()
+            },
+        ),
+        vec![(
+            Level::Warning,
+            String::from("swc-plugin-react-native-esbuild-module: top-level `await` is not supported in module factories, the module is not wrapped"),
+            String::from("const v = await x.load();"),
+        )]
+    );
+}
+
+#[test]
+fn live_binding_assignment() {
+    assert_eq!(
+        transform_with_options(
+            "import { a } from './a';\na = 1;",
+            ReactNativeEsbuildModuleOptions {
+                runtime_module: Some(true),
+                live_bindings: Some(true),
+                ..Default::default()
+            },
+        ),
+        vec![(
+            Level::Warning,
+            String::from("swc-plugin-react-native-esbuild-module: `a` is an imported binding, assigning to it throws a `TypeError`"),
+            String::from("a"),
+        )]
+    );
+}
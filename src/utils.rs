@@ -1,7 +1,11 @@
+use crate::module_collector::ImportModule;
 use swc_core::{
     atoms::Atom,
-    common::{Span, DUMMY_SP},
-    ecma::ast::*,
+    common::{EqIgnoreSpan, Span, DUMMY_SP},
+    ecma::{
+        ast::*,
+        utils::{is_valid_prop_ident, private_ident},
+    },
 };
 
 pub fn ident(sym: Atom) -> Ident {
@@ -20,6 +24,14 @@ pub fn str_lit_expr(value: String) -> Expr {
     }))
 }
 
+pub fn num_lit_expr(value: f64) -> Expr {
+    Expr::Lit(Lit::Num(Number {
+        span: DUMMY_SP,
+        value,
+        raw: None,
+    }))
+}
+
 pub fn fn_arg(expr: Expr) -> ExprOrSpread {
     ExprOrSpread {
         expr: Box::new(expr),
@@ -35,18 +47,33 @@ pub fn obj_member_expr(obj: Expr, prop: Ident) -> Expr {
     })
 }
 
-pub fn call_expr(callee: Expr, args: Vec<ExprOrSpread>) -> Expr {
-    Expr::Call(CallExpr {
+// `obj.name` or `obj["name"]` if the name is not a valid identifier.
+pub fn obj_prop_expr(obj: Expr, name: Atom) -> Expr {
+    if is_valid_prop_ident(&name) {
+        return obj_member_expr(obj, ident(name));
+    }
+    Expr::Member(MemberExpr {
         span: DUMMY_SP,
+        obj: Box::new(obj),
+        prop: MemberProp::Computed(ComputedPropName {
+            span: DUMMY_SP,
+            expr: Box::new(str_lit_expr(name.to_string())),
+        }),
+    })
+}
+
+pub fn call_expr(span: Span, callee: Expr, args: Vec<ExprOrSpread>) -> Expr {
+    Expr::Call(CallExpr {
+        span,
         callee: Callee::Expr(Box::new(callee)),
-        args: args,
+        args,
         type_args: None,
     })
 }
 
 pub fn decl_var_and_assign_stmt(name: Ident, span: Span, init: Expr) -> Stmt {
     Stmt::Decl(Decl::Var(Box::new(VarDecl {
-        span: DUMMY_SP,
+        span,
         kind: VarDeclKind::Var,
         declare: false,
         decls: vec![VarDeclarator {
@@ -60,3 +87,65 @@ pub fn decl_var_and_assign_stmt(name: Ident, span: Span, init: Expr) -> Stmt {
         }],
     })))
 }
+
+// Number of the directives (eg. `'use strict';`) at the beginning of the module.
+// Statements inserted at the top of the module should be placed after them.
+pub fn directive_prologue_len(body: &[ModuleItem]) -> usize {
+    body.iter()
+        .take_while(|module_item| {
+            matches!(
+                module_item,
+                ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. }))
+                    if matches!(&**expr, Expr::Lit(Lit::Str(_)))
+            )
+        })
+        .count()
+}
+
+// `N.b` for `import a = N.b;`
+pub fn ts_entity_name_expr(name: TsEntityName) -> Expr {
+    match name {
+        TsEntityName::Ident(ident) => Expr::Ident(ident),
+        TsEntityName::TsQualifiedName(qualified_name) => {
+            let TsQualifiedName { left, right } = *qualified_name;
+            obj_member_expr(ts_entity_name_expr(left), right)
+        }
+    }
+}
+
+// `a` for `a` and `"a"`
+pub fn module_export_name(name: &ModuleExportName) -> Atom {
+    match name {
+        ModuleExportName::Ident(ident) => ident.sym.clone(),
+        ModuleExportName::Str(str) => str.value.clone(),
+    }
+}
+
+// `_react` for `react`, `_components` for `@app/components`
+pub fn module_namespace_ident(module_src: &str) -> Ident {
+    let name = module_src
+        .rsplit('/')
+        .find(|segment| !segment.is_empty() && *segment != "." && *segment != "..")
+        .unwrap_or("module")
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect::<String>();
+    private_ident!(format!("_{}", name))
+}
+
+// Group imports by the module source (and the import attributes) in order of appearance.
+pub fn group_imports_by_module_src(imports: Vec<ImportModule>) -> Vec<(String, Vec<ImportModule>)> {
+    let mut groups: Vec<(String, Vec<ImportModule>)> = Vec::new();
+    for import_module in imports {
+        match groups.iter_mut().find(|(module_src, group)| {
+            *module_src == import_module.module_src
+                && group[0]
+                    .attributes
+                    .eq_ignore_span(&import_module.attributes)
+        }) {
+            Some((_, group)) => group.push(import_module),
+            None => groups.push((import_module.module_src.clone(), vec![import_module])),
+        }
+    }
+    groups
+}